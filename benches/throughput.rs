@@ -0,0 +1,60 @@
+//! Generation and solving throughput in cells per second.
+//!
+//! The largest mazes take minutes per iteration for the slower algorithms, so they are only benchmarked when the
+//! `MAZE_BENCH_HUGE` environment variable is set.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, SamplingMode, Throughput};
+use maze_algorithms::{algorithms::AlgorithmLabel, astar, generate, glam::UVec2};
+
+const SEED: u64 = 0x5eed;
+const SIZES: &[u32] = &[64, 512];
+const HUGE_SIZE: u32 = 4096;
+
+fn sizes() -> Vec<u32> {
+    let mut sizes = SIZES.to_vec();
+
+    if std::env::var_os("MAZE_BENCH_HUGE").is_some() {
+        sizes.push(HUGE_SIZE);
+    }
+
+    sizes
+}
+
+fn generation(c: &mut Criterion) {
+    for &label in AlgorithmLabel::ALL {
+        let mut group = c.benchmark_group(format!("generate/{}", label.name()));
+        group.sample_size(10).sampling_mode(SamplingMode::Flat);
+
+        for size in sizes() {
+            group.throughput(Throughput::Elements(size as u64 * size as u64));
+            group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, &size| {
+                b.iter(|| generate(label, black_box(UVec2::splat(size)), SEED));
+            });
+        }
+
+        group.finish();
+    }
+}
+
+fn solving(c: &mut Criterion) {
+    let mut group = c.benchmark_group("solve/astar");
+    group.sample_size(10).sampling_mode(SamplingMode::Flat);
+
+    for size in sizes() {
+        // Depth first search makes long, winding solutions that visit much of the maze.
+        let maze = generate(AlgorithmLabel::DepthFirstSearch, UVec2::splat(size), SEED);
+        let goal = maze.size - 1;
+
+        group.throughput(Throughput::Elements(size as u64 * size as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &maze, |b, maze| {
+            b.iter(|| astar::astar(black_box(UVec2::ZERO), black_box(goal), maze));
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, generation, solving);
+criterion_main!(benches);
@@ -0,0 +1,80 @@
+use glam::UVec2;
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+use crate::maze::MazeState;
+
+use super::{Algorithm, Wilson};
+
+#[derive(Debug, Clone)]
+pub struct AldousBroder {
+    seed: u64,
+    rng: SmallRng,
+    hybrid_threshold: Option<f32>,
+    remaining: u32,
+    wilson: Option<Wilson>,
+}
+
+impl AldousBroder {
+    /// Creates the generator. With a `hybrid_threshold`, it switches to Wilson's algorithm once that fraction of
+    /// the cells has been visited, since the random walk takes longer and longer to find the last few cells.
+    pub fn new(seed: u64, hybrid_threshold: Option<f32>) -> AldousBroder {
+        AldousBroder {
+            seed,
+            rng: SmallRng::seed_from_u64(seed),
+            hybrid_threshold,
+            remaining: 0,
+            wilson: None,
+        }
+    }
+}
+
+impl Algorithm for AldousBroder {
+    fn initialize(&mut self, maze: &mut MazeState) {
+        self.rng = SmallRng::seed_from_u64(self.seed);
+        self.wilson = None;
+        self.remaining = maze.size.x * maze.size.y - 1;
+
+        let x = self.rng.random_range(0..maze.size.x);
+        let y = self.rng.random_range(0..maze.size.y);
+
+        maze.head = UVec2::new(x, y);
+        maze.set_visited(maze.head);
+        maze.set_finalized(maze.head);
+    }
+
+    fn step(&mut self, maze: &mut MazeState) {
+        if let Some(wilson) = &mut self.wilson {
+            wilson.step(maze);
+            return;
+        }
+
+        if self.remaining == 0 {
+            maze.finish();
+            return;
+        }
+
+        let Some(dir) = (!maze.edges(maze.head)).choose(&mut self.rng) else {
+            return;
+        };
+        let next = dir.offset(maze.head);
+
+        if !maze.finalized(next) {
+            maze.neighbors.open(maze.head, dir);
+            maze.set_visited(next);
+            maze.set_finalized(next);
+            self.remaining -= 1;
+        }
+
+        maze.head = next;
+
+        if let Some(threshold) = self.hybrid_threshold {
+            let cells = (maze.size.x * maze.size.y) as f32;
+
+            if self.remaining > 0 && 1.0 - self.remaining as f32 / cells >= threshold {
+                let mut wilson = Wilson::new(self.rng.random());
+                wilson.resume(maze);
+                self.wilson = Some(wilson);
+            }
+        }
+    }
+}
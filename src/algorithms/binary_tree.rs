@@ -0,0 +1,58 @@
+use glam::UVec2;
+use rand::{rngs::SmallRng, SeedableRng};
+
+use crate::{
+    direction::{Corner, Directions},
+    maze::MazeState,
+};
+
+use super::Algorithm;
+
+#[derive(Debug, Clone)]
+pub struct BinaryTree {
+    seed: u64,
+    rng: SmallRng,
+    bias: Corner,
+}
+
+impl BinaryTree {
+    /// Creates the generator. Every cell opens one of the two walls facing `bias`, so all passages lead there.
+    pub fn new(seed: u64, bias: Corner) -> BinaryTree {
+        BinaryTree {
+            seed,
+            rng: SmallRng::seed_from_u64(seed),
+            bias,
+        }
+    }
+}
+
+impl Algorithm for BinaryTree {
+    fn initialize(&mut self, maze: &mut MazeState) {
+        self.rng = SmallRng::seed_from_u64(self.seed);
+        maze.head = UVec2::ZERO;
+    }
+
+    fn step(&mut self, maze: &mut MazeState) {
+        if maze.head.y == maze.size.y {
+            maze.finish();
+            return;
+        }
+
+        let (vertical, horizontal) = self.bias.directions();
+        let dirs = (Directions::from(vertical) | Directions::from(horizontal)) & !maze.edges(maze.head);
+
+        if let Some(dir) = dirs.choose(&mut self.rng) {
+            maze.neighbors.open(maze.head, dir);
+        }
+
+        maze.set_visited(maze.head);
+        maze.set_finalized(maze.head);
+
+        maze.head.x += 1;
+
+        if maze.head.x == maze.size.x {
+            maze.head.x = 0;
+            maze.head.y += 1;
+        }
+    }
+}
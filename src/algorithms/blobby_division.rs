@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+
+use glam::UVec2;
+use rand::{rngs::SmallRng, seq::index, Rng, SeedableRng};
+
+use crate::{direction::Directions, maze::MazeState};
+
+use super::Algorithm;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Blob {
+    A,
+    B,
+}
+
+#[derive(Debug, Clone)]
+pub struct BlobbyDivision {
+    seed: u64,
+    rng: SmallRng,
+    regions: Vec<Vec<UVec2>>,
+    region: Vec<UVec2>,
+    blobs: HashMap<UVec2, Option<Blob>>,
+    frontier: Vec<UVec2>,
+}
+
+impl BlobbyDivision {
+    pub fn new(seed: u64) -> BlobbyDivision {
+        BlobbyDivision {
+            seed,
+            rng: SmallRng::seed_from_u64(seed),
+            regions: Vec::new(),
+            region: Vec::new(),
+            blobs: HashMap::new(),
+            frontier: Vec::new(),
+        }
+    }
+
+    fn assign(&mut self, maze: &mut MazeState, cell: UVec2, blob: Blob) {
+        self.blobs.insert(cell, Some(blob));
+        self.frontier.push(cell);
+
+        match blob {
+            Blob::A => maze.set_visited(cell),
+            Blob::B => maze.set_finalized(cell),
+        }
+    }
+
+    /// Seeds both blobs in a new region.
+    fn start(&mut self, maze: &mut MazeState, region: Vec<UVec2>) {
+        self.blobs = region.iter().map(|&c| (c, None)).collect();
+
+        let seeds = index::sample(&mut self.rng, region.len(), 2);
+        self.assign(maze, region[seeds.index(0)], Blob::A);
+        self.assign(maze, region[seeds.index(1)], Blob::B);
+
+        self.region = region;
+    }
+
+    /// Grows a blob by one cell.
+    fn grow(&mut self, maze: &mut MazeState) {
+        let index = self.rng.random_range(0..self.frontier.len());
+        let cell = self.frontier[index];
+        let blob = self.blobs[&cell];
+
+        let dirs = Directions::from_fn(|d| {
+            d.checked_offset(cell)
+                .is_some_and(|c| self.blobs.get(&c).is_some_and(|b| b.is_none()))
+        });
+
+        if let Some(dir) = dirs.choose(&mut self.rng) {
+            maze.head = dir.offset(cell);
+            self.assign(maze, maze.head, blob.unwrap());
+        } else {
+            self.frontier.swap_remove(index);
+        }
+    }
+
+    /// Walls off the two grown blobs from each other, leaving a single gap, and queues them to be divided further.
+    fn divide(&mut self, maze: &mut MazeState) {
+        let mut boundary = Vec::new();
+
+        for &cell in &self.region {
+            if self.blobs[&cell] != Some(Blob::A) {
+                continue;
+            }
+
+            for dir in maze.neighbors[cell] {
+                if self.blobs.get(&dir.offset(cell)) == Some(&Some(Blob::B)) {
+                    boundary.push((cell, dir));
+                }
+            }
+        }
+
+        let gap = self.rng.random_range(0..boundary.len());
+
+        for (i, &(cell, dir)) in boundary.iter().enumerate() {
+            if i != gap {
+                maze.neighbors.close(cell, dir);
+            }
+        }
+
+        let (cell, dir) = boundary[gap];
+        maze.wall_head = maze.wall_index(cell, dir);
+
+        let (a, b): (Vec<_>, Vec<_>) = self.region.drain(..).partition(|c| self.blobs[c] == Some(Blob::A));
+
+        for &c in a.iter().chain(&b) {
+            maze.unset_visited(c);
+            maze.unset_finalized(c);
+        }
+
+        self.regions.push(b);
+        self.regions.push(a);
+        self.blobs.clear();
+    }
+}
+
+impl Algorithm for BlobbyDivision {
+    fn initialize(&mut self, maze: &mut MazeState) {
+        self.rng = SmallRng::seed_from_u64(self.seed);
+        self.regions.clear();
+        self.region.clear();
+        self.blobs.clear();
+        self.frontier.clear();
+
+        let mut region = Vec::with_capacity((maze.size.x * maze.size.y) as usize);
+
+        for x in 0..maze.size.x {
+            for y in 0..maze.size.y {
+                let p = UVec2::new(x, y);
+                let e = maze.edges(p);
+
+                maze.neighbors[p] = !e;
+                region.push(p);
+            }
+        }
+
+        self.regions.push(region);
+    }
+
+    fn step(&mut self, maze: &mut MazeState) {
+        if !self.frontier.is_empty() {
+            self.grow(maze);
+            return;
+        }
+
+        if !self.region.is_empty() {
+            self.divide(maze);
+            return;
+        }
+
+        let Some(region) = self.regions.pop() else {
+            maze.finish();
+            return;
+        };
+
+        // Any region of fewer than four cells is already a tree, since it cannot contain a loop.
+        if region.len() < 4 {
+            for c in region {
+                maze.set_visited(c);
+                maze.set_finalized(c);
+            }
+        } else {
+            self.start(maze, region);
+        }
+    }
+}
@@ -0,0 +1,328 @@
+use std::{collections::VecDeque, fmt, str::FromStr};
+
+use glam::{IVec2, UVec2};
+use rand::{rngs::SmallRng, seq::SliceRandom, Rng, SeedableRng};
+
+use crate::{direction::Direction, disjoint_sets::DisjointSets, maze::MazeState};
+
+use super::Algorithm;
+
+/// The most generations [`CellularAutomaton`] runs before it stops, if the pattern has not settled by then.
+const MAX_GENERATIONS: u32 = 1000;
+
+/// A Life-like rule in B/S notation, such as `B3/S23` for Conway's Game of Life.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LifeRule {
+    /// Whether a dead cell with the given number of live neighbors comes alive.
+    pub birth: [bool; 9],
+    /// Whether a live cell with the given number of live neighbors stays alive.
+    pub survival: [bool; 9],
+}
+
+impl LifeRule {
+    /// `B3/S12345`, which grows long, winding corridors.
+    pub const MAZE: LifeRule = LifeRule::new(&[3], &[1, 2, 3, 4, 5]);
+    /// `B3/S1234`, which grows shorter corridors than [`LifeRule::MAZE`].
+    pub const MAZECTRIC: LifeRule = LifeRule::new(&[3], &[1, 2, 3, 4]);
+
+    pub const fn new(birth: &[usize], survival: &[usize]) -> LifeRule {
+        let mut rule = LifeRule {
+            birth: [false; 9],
+            survival: [false; 9],
+        };
+
+        let mut i = 0;
+        while i < birth.len() {
+            rule.birth[birth[i]] = true;
+            i += 1;
+        }
+
+        let mut i = 0;
+        while i < survival.len() {
+            rule.survival[survival[i]] = true;
+            i += 1;
+        }
+
+        rule
+    }
+}
+
+impl Default for LifeRule {
+    fn default() -> Self {
+        LifeRule::MAZE
+    }
+}
+
+impl fmt::Display for LifeRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "B")?;
+        for n in (0..9).filter(|&n| self.birth[n]) {
+            write!(f, "{n}")?;
+        }
+
+        write!(f, "/S")?;
+        for n in (0..9).filter(|&n| self.survival[n]) {
+            write!(f, "{n}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for LifeRule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rule = LifeRule {
+            birth: [false; 9],
+            survival: [false; 9],
+        };
+        let (mut birth, mut survival) = (false, false);
+
+        for part in s.trim().split('/') {
+            let (counts, seen) = match part.chars().next().map(|c| c.to_ascii_uppercase()) {
+                Some('B') => (&mut rule.birth, &mut birth),
+                Some('S') => (&mut rule.survival, &mut survival),
+                _ => return Err(format!("invalid rule `{s}`, expected something like B3/S12345")),
+            };
+
+            if *seen {
+                return Err(format!("invalid rule `{s}`, each of B and S may only appear once"));
+            }
+            *seen = true;
+
+            for c in part[1..].chars() {
+                match c.to_digit(10) {
+                    Some(n) if n < 9 => counts[n as usize] = true,
+                    _ => return Err(format!("invalid neighbor count `{c}` in rule `{s}`")),
+                }
+            }
+        }
+
+        if !birth || !survival {
+            return Err(format!("invalid rule `{s}`, expected both a B and an S part"));
+        }
+
+        Ok(rule)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CellularAutomaton {
+    seed: u64,
+    rng: SmallRng,
+    rule: LifeRule,
+    live: Vec<bool>,
+    generation: u32,
+}
+
+impl CellularAutomaton {
+    /// Creates the generator. Every step runs one generation of `rule`, and once the pattern settles the live cells
+    /// become solid rock while the dead cells are joined into caves. Finally, the shortest tunnels that join all the
+    /// caves are dug, and the remaining rock is excluded from the maze.
+    pub fn new(seed: u64, rule: LifeRule) -> CellularAutomaton {
+        CellularAutomaton {
+            seed,
+            rng: SmallRng::seed_from_u64(seed),
+            rule,
+            live: Vec::new(),
+            generation: 0,
+        }
+    }
+
+    fn is_live(&self, maze: &MazeState, cell: IVec2) -> bool {
+        cell.cmpge(IVec2::ZERO).all()
+            && cell.cmplt(maze.size.as_ivec2()).all()
+            && self.live[(cell.y as u32 * maze.size.x + cell.x as u32) as usize]
+    }
+
+    /// Turns live cells into solid rock and opens every wall between two dead cells.
+    fn carve(&self, maze: &mut MazeState) {
+        for y in 0..maze.size.y {
+            for x in 0..maze.size.x {
+                let cell = UVec2::new(x, y);
+
+                if self.is_live(maze, cell.as_ivec2()) {
+                    continue;
+                }
+
+                maze.set_finalized(cell);
+
+                for dir in [Direction::East, Direction::North] {
+                    if !maze.edges(cell).contains(dir) && !self.is_live(maze, dir.offset(cell).as_ivec2()) {
+                        maze.neighbors.open(cell, dir);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Joins the caves through as little rock as possible and excludes the rest of the rock from the maze.
+    ///
+    /// A breadth first search from every cave at once finds the nearest cave to each rock cell. Wherever the areas of
+    /// two caves meet there is a tunnel between them, and the shortest tunnels are dug first, skipping any between
+    /// caves that are already joined, the same way Kruskal's algorithm would.
+    fn connect(&mut self, maze: &mut MazeState) {
+        let width = maze.size.x;
+        let index = move |cell: UVec2| (cell.y * width + cell.x) as usize;
+        let len = (maze.size.x * maze.size.y) as usize;
+
+        let mut caves = DisjointSets::new(len);
+        // The number of rock cells between each cell and its nearest cave, the way there, and a cell of that cave.
+        let mut depth = vec![u32::MAX; len];
+        let mut towards: Vec<Option<Direction>> = vec![None; len];
+        let mut nearest: Vec<usize> = (0..len).collect();
+        let mut queue = VecDeque::new();
+
+        for y in 0..maze.size.y {
+            for x in 0..maze.size.x {
+                let cell = UVec2::new(x, y);
+
+                if self.live[index(cell)] {
+                    continue;
+                }
+
+                depth[index(cell)] = 0;
+                queue.push_back(cell);
+
+                for dir in maze.neighbors[cell] {
+                    caves.union(index(cell), index(dir.offset(cell)));
+                }
+            }
+        }
+
+        while let Some(cell) = queue.pop_front() {
+            for dir in !maze.edges(cell) {
+                let next = dir.offset(cell);
+
+                if depth[index(next)] == u32::MAX {
+                    depth[index(next)] = depth[index(cell)] + 1;
+                    towards[index(next)] = Some(-dir);
+                    nearest[index(next)] = nearest[index(cell)];
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        let mut tunnels = Vec::new();
+
+        for y in 0..maze.size.y {
+            for x in 0..maze.size.x {
+                let cell = UVec2::new(x, y);
+
+                for dir in [Direction::East, Direction::North] {
+                    if maze.edges(cell).contains(dir) {
+                        continue;
+                    }
+
+                    let (a, b) = (index(cell), index(dir.offset(cell)));
+
+                    if depth[a] != u32::MAX && caves.find(nearest[a]) != caves.find(nearest[b]) {
+                        tunnels.push((depth[a] + depth[b], cell, dir));
+                    }
+                }
+            }
+        }
+
+        // Shuffle first so that tunnels of the same length are dug in a random order.
+        tunnels.shuffle(&mut self.rng);
+        tunnels.sort_by_key(|&(length, ..)| length);
+
+        for (_, cell, dir) in tunnels {
+            let other = dir.offset(cell);
+
+            if !caves.union(nearest[index(cell)], nearest[index(other)]) {
+                continue;
+            }
+
+            maze.neighbors.open(cell, dir);
+
+            for mut cell in [cell, other] {
+                while let Some(dir) = towards[index(cell)] {
+                    self.live[index(cell)] = false;
+                    maze.set_finalized(cell);
+                    maze.neighbors.open(cell, dir);
+                    cell = dir.offset(cell);
+                }
+            }
+        }
+
+        for y in 0..maze.size.y {
+            for x in 0..maze.size.x {
+                let cell = UVec2::new(x, y);
+
+                if self.live[index(cell)] {
+                    maze.set_excluded(cell);
+                }
+            }
+        }
+    }
+}
+
+impl Algorithm for CellularAutomaton {
+    fn initialize(&mut self, maze: &mut MazeState) {
+        self.rng = SmallRng::seed_from_u64(self.seed);
+        self.generation = 0;
+        self.live = vec![false; (maze.size.x * maze.size.y) as usize];
+
+        // Seed a random soup in the middle of the maze and let the rule grow it outwards.
+        let min = maze.size / 4;
+        let max = (maze.size - min).max(min + 1);
+
+        for y in min.y..max.y {
+            for x in min.x..max.x {
+                if self.rng.random_bool(0.5) {
+                    let cell = UVec2::new(x, y);
+
+                    self.live[(y * maze.size.x + x) as usize] = true;
+                    maze.set_visited(cell);
+                }
+            }
+        }
+
+        maze.head = maze.size;
+    }
+
+    fn step(&mut self, maze: &mut MazeState) {
+        let mut next = self.live.clone();
+
+        for y in 0..maze.size.y {
+            for x in 0..maze.size.x {
+                let cell = IVec2::new(x as i32, y as i32);
+                let mut count = 0;
+
+                for dy in -1..=1 {
+                    for dx in -1..=1 {
+                        if (dx, dy) != (0, 0) && self.is_live(maze, cell + IVec2::new(dx, dy)) {
+                            count += 1;
+                        }
+                    }
+                }
+
+                let i = (y * maze.size.x + x) as usize;
+                next[i] = if self.live[i] {
+                    self.rule.survival[count]
+                } else {
+                    self.rule.birth[count]
+                };
+
+                if next[i] {
+                    maze.set_visited(cell.as_uvec2());
+                } else {
+                    maze.unset_visited(cell.as_uvec2());
+                }
+            }
+        }
+
+        self.generation += 1;
+
+        let settled = next == self.live;
+        self.live = next;
+
+        if settled || self.generation >= MAX_GENERATIONS {
+            self.carve(maze);
+            self.connect(maze);
+            maze.finish();
+        }
+    }
+}
@@ -9,14 +9,16 @@ use super::Algorithm;
 
 #[derive(Debug, Clone)]
 pub struct DepthFirstSearch {
+    seed: u64,
     rng: SmallRng,
     visited: VecDeque<UVec2>,
 }
 
 impl DepthFirstSearch {
-    pub fn new() -> DepthFirstSearch {
+    pub fn new(seed: u64) -> DepthFirstSearch {
         DepthFirstSearch {
-            rng: SmallRng::seed_from_u64(0),
+            seed,
+            rng: SmallRng::seed_from_u64(seed),
             visited: VecDeque::new(),
         }
     }
@@ -24,7 +26,7 @@ impl DepthFirstSearch {
 
 impl Algorithm for DepthFirstSearch {
     fn initialize(&mut self, maze: &mut MazeState) {
-        self.rng = SmallRng::from_rng(&mut rand::rng());
+        self.rng = SmallRng::seed_from_u64(self.seed);
         self.visited.clear();
 
         let x = self.rng.random_range(0..maze.size.x);
@@ -1,6 +1,5 @@
-use std::collections::{HashMap, HashSet};
-
 use glam::UVec2;
+use indexmap::{IndexMap, IndexSet};
 use rand::{rngs::SmallRng, seq::SliceRandom, Rng, SeedableRng};
 
 use crate::{direction::Direction, maze::MazeState};
@@ -9,6 +8,7 @@ use super::Algorithm;
 
 #[derive(Debug, Clone)]
 pub struct Eller {
+    seed: u64,
     rng: SmallRng,
     row: RowState,
     next_row: RowState,
@@ -17,9 +17,10 @@ pub struct Eller {
 }
 
 impl Eller {
-    pub fn new() -> Eller {
+    pub fn new(seed: u64) -> Eller {
         Eller {
-            rng: SmallRng::seed_from_u64(0),
+            seed,
+            rng: SmallRng::seed_from_u64(seed),
             row: RowState::new(0, 0),
             next_row: RowState::new(0, 0),
             cells: Vec::new(),
@@ -30,7 +31,7 @@ impl Eller {
 
 impl Algorithm for Eller {
     fn initialize(&mut self, maze: &mut MazeState) {
-        self.rng = SmallRng::from_rng(&mut rand::rng());
+        self.rng = SmallRng::seed_from_u64(self.seed);
         self.row = RowState::new(0, maze.size.x);
 
         maze.head = UVec2::ZERO;
@@ -58,6 +59,11 @@ impl Algorithm for Eller {
 
                 let edges = maze.edges(maze.head);
                 if edges.west {
+                    // In a one cell wide maze no later cell will look back and give this one a set.
+                    if edges.east {
+                        self.row.set_for(maze.head);
+                    }
+
                     maze.head.x += 1;
                     return;
                 }
@@ -100,7 +106,7 @@ impl Algorithm for Eller {
 #[derive(Debug, Clone)]
 struct RowState {
     width: u32,
-    cells_in_set: HashMap<usize, HashSet<UVec2>>,
+    cells_in_set: IndexMap<usize, IndexSet<UVec2>>,
     set_for_cell: Vec<Option<usize>>,
     next_set: usize,
 }
@@ -109,7 +115,7 @@ impl RowState {
     pub fn new(start_set: usize, width: u32) -> RowState {
         RowState {
             width,
-            cells_in_set: HashMap::new(),
+            cells_in_set: IndexMap::new(),
             set_for_cell: vec![None; width as usize],
             next_set: start_set,
         }
@@ -138,7 +144,7 @@ impl RowState {
             self.cells_in_set.get_mut(&winner).unwrap().insert(cell);
         }
 
-        self.cells_in_set.remove(&loser);
+        self.cells_in_set.shift_remove(&loser);
     }
 
     pub fn next(&self) -> RowState {
@@ -6,28 +6,91 @@ use crate::{direction::Directions, maze::MazeState};
 
 use super::Algorithm;
 
+/// A single way for [`GrowingTree`] to pick the next cell to grow from among the cells that may still have unvisited
+/// neighbors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GrowingTreePick {
+    /// The most recently added cell, which behaves like depth first search.
+    Newest,
+    /// The least recently added cell, which makes long, straight corridors.
+    Oldest,
+    /// A random cell, which behaves like Prim's algorithm.
+    Random,
+    /// The cell in the middle of the list.
+    Middle,
+}
+
+/// How [`GrowingTree`] picks the next cell to grow from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GrowingTreeStrategy {
+    /// Always picks the same way.
+    Single(GrowingTreePick),
+    /// Picks with `first` with probability `weight` and with `second` otherwise. A `weight` that is not a number
+    /// counts as zero.
+    Mixed {
+        first: GrowingTreePick,
+        second: GrowingTreePick,
+        weight: f32,
+    },
+}
+
+impl Default for GrowingTreeStrategy {
+    fn default() -> Self {
+        GrowingTreeStrategy::Mixed {
+            first: GrowingTreePick::Newest,
+            second: GrowingTreePick::Random,
+            weight: 0.75,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct GrowingTree {
+    seed: u64,
     rng: SmallRng,
+    strategy: GrowingTreeStrategy,
     visited: IndexSet<UVec2>,
-    path_length: u32,
 }
 
 impl GrowingTree {
-    pub fn new() -> GrowingTree {
+    pub fn new(seed: u64, strategy: GrowingTreeStrategy) -> GrowingTree {
         GrowingTree {
-            rng: SmallRng::seed_from_u64(0),
+            seed,
+            rng: SmallRng::seed_from_u64(seed),
+            strategy,
             visited: IndexSet::new(),
-            path_length: 0,
+        }
+    }
+
+    fn choose_index(&mut self) -> usize {
+        let len = self.visited.len();
+
+        let pick = match self.strategy {
+            GrowingTreeStrategy::Single(pick) => pick,
+            GrowingTreeStrategy::Mixed { first, second, weight } => {
+                let weight = if weight.is_nan() { 0.0 } else { weight.clamp(0.0, 1.0) };
+
+                if self.rng.random_bool(weight as f64) {
+                    first
+                } else {
+                    second
+                }
+            },
+        };
+
+        match pick {
+            GrowingTreePick::Newest => len - 1,
+            GrowingTreePick::Oldest => 0,
+            GrowingTreePick::Random => self.rng.random_range(0..len),
+            GrowingTreePick::Middle => len / 2,
         }
     }
 }
 
 impl Algorithm for GrowingTree {
     fn initialize(&mut self, maze: &mut MazeState) {
-        self.rng = SmallRng::from_rng(&mut rand::rng());
+        self.rng = SmallRng::seed_from_u64(self.seed);
         self.visited.clear();
-        self.path_length = 0;
 
         let x = self.rng.random_range(0..maze.size.x);
         let y = self.rng.random_range(0..maze.size.y);
@@ -39,40 +102,26 @@ impl Algorithm for GrowingTree {
     }
 
     fn step(&mut self, maze: &mut MazeState) {
-        if maze.visited(maze.head) {
-            let mut dirs = !Directions::from_fn(|d| d.checked_offset(maze.head).is_some_and(|c| maze.visited(c)));
-            dirs &= !maze.edges(maze.head);
+        if self.visited.is_empty() {
+            maze.finish();
+            return;
+        }
 
-            if let Some(dir) = dirs.choose(&mut self.rng) {
-                maze.neighbors.open(maze.head, dir);
-                maze.head = dir.offset(maze.head);
+        let index = self.choose_index();
+        maze.head = *self.visited.get_index(index).unwrap();
 
-                self.path_length += 1;
+        let mut dirs = !Directions::from_fn(|d| d.checked_offset(maze.head).is_some_and(|c| maze.visited(c)));
+        dirs &= !maze.edges(maze.head);
 
-                if self.path_length < 4 {
-                    self.visited.insert(maze.head);
-                    maze.set_visited(maze.head);
-                } else {
-                    self.path_length = 0;
-                }
+        if let Some(dir) = dirs.choose(&mut self.rng) {
+            maze.neighbors.open(maze.head, dir);
+            maze.head = dir.offset(maze.head);
 
-                return;
-            } else {
-                self.visited.shift_remove(&maze.head);
-                maze.set_finalized(maze.head);
-                self.path_length = 0;
-            }
-        } else {
             self.visited.insert(maze.head);
             maze.set_visited(maze.head);
-        }
-
-        if self.visited.is_empty() {
-            maze.finish();
         } else {
-            let index = self.rng.random_range(0..self.visited.len());
-            let next = self.visited.get_index(index).unwrap();
-            maze.head = *next;
+            self.visited.shift_remove_index(index);
+            maze.set_finalized(maze.head);
         }
     }
 }
@@ -0,0 +1,111 @@
+use glam::UVec2;
+use rand::{rngs::SmallRng, seq::SliceRandom, Rng, SeedableRng};
+
+use crate::{direction::Directions, maze::MazeState};
+
+use super::Algorithm;
+
+/// The order in which [`HuntAndKill`] scans the maze for a new cell to continue from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HuntOrder {
+    /// Scan row by row, starting at the bottom.
+    #[default]
+    RowMajor,
+    /// Scan the cells in a random order that is fixed for the whole maze.
+    Random,
+}
+
+#[derive(Debug, Clone)]
+pub struct HuntAndKill {
+    seed: u64,
+    rng: SmallRng,
+    order: HuntOrder,
+    scan: Vec<UVec2>,
+    scan_start: usize,
+    scan_index: usize,
+    hunting: bool,
+}
+
+impl HuntAndKill {
+    pub fn new(seed: u64, order: HuntOrder) -> HuntAndKill {
+        HuntAndKill {
+            seed,
+            rng: SmallRng::seed_from_u64(seed),
+            order,
+            scan: Vec::new(),
+            scan_start: 0,
+            scan_index: 0,
+            hunting: false,
+        }
+    }
+}
+
+impl Algorithm for HuntAndKill {
+    fn initialize(&mut self, maze: &mut MazeState) {
+        self.rng = SmallRng::seed_from_u64(self.seed);
+        self.scan.clear();
+        self.scan_start = 0;
+        self.scan_index = 0;
+        self.hunting = false;
+
+        for y in 0..maze.size.y {
+            for x in 0..maze.size.x {
+                self.scan.push(UVec2::new(x, y));
+            }
+        }
+
+        if self.order == HuntOrder::Random {
+            self.scan.shuffle(&mut self.rng);
+        }
+
+        let x = self.rng.random_range(0..maze.size.x);
+        let y = self.rng.random_range(0..maze.size.y);
+
+        maze.head = UVec2::new(x, y);
+        maze.set_visited(maze.head);
+    }
+
+    fn step(&mut self, maze: &mut MazeState) {
+        if !self.hunting {
+            let mut dirs = !Directions::from_fn(|d| d.checked_offset(maze.head).is_some_and(|c| maze.visited(c)));
+            dirs &= !maze.edges(maze.head);
+
+            if let Some(dir) = dirs.choose(&mut self.rng) {
+                maze.neighbors.open(maze.head, dir);
+                maze.head = dir.offset(maze.head);
+                maze.set_visited(maze.head);
+            } else {
+                maze.set_finalized(maze.head);
+                self.hunting = true;
+                self.scan_index = self.scan_start;
+            }
+
+            return;
+        }
+
+        let Some(&cell) = self.scan.get(self.scan_index) else {
+            maze.finish();
+            return;
+        };
+
+        maze.head = cell;
+        self.scan_index += 1;
+
+        let visited = Directions::from_fn(|d| d.checked_offset(cell).is_some_and(|c| maze.visited(c)));
+
+        if maze.visited(cell) {
+            // Every cell before `scan_start` is finalized, so later hunts can skip them.
+            if (!visited & !maze.edges(cell)).is_empty() {
+                maze.set_finalized(cell);
+
+                if self.scan_index == self.scan_start + 1 {
+                    self.scan_start += 1;
+                }
+            }
+        } else if let Some(dir) = (visited & !maze.edges(cell)).choose(&mut self.rng) {
+            maze.neighbors.open(cell, dir);
+            maze.set_visited(cell);
+            self.hunting = false;
+        }
+    }
+}
@@ -1,96 +1,138 @@
 use std::collections::HashSet;
 
 use glam::UVec2;
-use rand::{rngs::SmallRng, seq::SliceRandom, SeedableRng};
+use rand::{rngs::SmallRng, seq::SliceRandom, Rng, SeedableRng};
 
-use crate::{direction::Direction, maze::MazeState};
+use crate::{direction::Direction, disjoint_sets::DisjointSets, maze::MazeState, noise::ValueNoise};
 
 use super::Algorithm;
 
+/// How likely [`Kruskal`] is to carve each passage early. Passages with a higher weight tend to be carved first, so
+/// they end up open more often; a weight of zero means a passage is only carved if nothing else connects its cells.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum KruskalWeights {
+    /// Every passage is equally likely, which gives the usual unbiased ordering.
+    #[default]
+    Uniform,
+    /// East-west passages have weight `horizontal` and north-south passages have weight `1 - horizontal`.
+    Directional { horizontal: f32 },
+    /// Every passage has the mean weight of the two cells it joins, indexed by `y * width + x`. Missing cells have a
+    /// weight of one.
+    Cells(Vec<f32>),
+    /// Passages are weighted by smooth value noise with features roughly `scale` cells wide.
+    Noise { scale: f32 },
+}
+
 #[derive(Debug, Clone)]
 pub struct Kruskal {
+    seed: u64,
     rng: SmallRng,
+    weights: KruskalWeights,
     walls: Vec<u32>,
     wall_set: HashSet<u32>,
-    parent: Vec<usize>,
-    rank: Vec<usize>,
+    sets: DisjointSets,
 }
 
 impl Kruskal {
-    pub fn new() -> Kruskal {
+    pub fn new(seed: u64, weights: KruskalWeights) -> Kruskal {
         Kruskal {
-            rng: SmallRng::seed_from_u64(0),
+            seed,
+            rng: SmallRng::seed_from_u64(seed),
+            weights,
             walls: Vec::new(),
             wall_set: HashSet::new(),
-            parent: Vec::new(),
-            rank: Vec::new(),
+            sets: DisjointSets::default(),
         }
     }
 
-    fn find_parent(&mut self, i: usize) -> usize {
-        if self.parent[i] != i {
-            self.parent[i] = self.find_parent(self.parent[i]);
+    /// The two cells on either side of the given wall.
+    fn wall_cells(maze: &MazeState, wall: u32) -> (UVec2, UVec2) {
+        if wall >= (maze.size.x - 1) * maze.size.y {
+            // Vertical wall
+            let pos = wall - (maze.size.x - 1) * maze.size.y;
+            let pos = UVec2::new(pos % maze.size.x, pos / maze.size.x);
+            (pos, pos + UVec2::Y)
+        } else {
+            // Horizontal wall
+            let pos = UVec2::new(wall % (maze.size.x - 1), wall / (maze.size.x - 1));
+            (pos, pos + UVec2::X)
         }
-
-        self.parent[i]
     }
 
-    fn union(&mut self, x: usize, y: usize) {
-        match self.rank[x].cmp(&self.rank[y]) {
-            std::cmp::Ordering::Less => self.parent[x] = y,
-            std::cmp::Ordering::Greater => self.parent[y] = x,
-            std::cmp::Ordering::Equal => {
-                self.parent[y] = x;
-                self.rank[x] += 1;
-            },
+    /// Orders `walls` so that popping from the back yields a weighted random permutation, using the keys from
+    /// Efraimidis and Spirakis' weighted sampling.
+    fn sort_walls(&mut self, maze: &MazeState) {
+        let noise = match self.weights {
+            KruskalWeights::Noise { scale } => Some(ValueNoise::new(&mut self.rng, maze.size, scale)),
+            _ => None,
+        };
+
+        let mut keys = Vec::with_capacity(self.walls.len());
+
+        for &wall in &self.walls {
+            let (a, b) = Kruskal::wall_cells(maze, wall);
+
+            let weight = match &self.weights {
+                KruskalWeights::Uniform => 1.0,
+                KruskalWeights::Directional { horizontal } => {
+                    if a.y == b.y {
+                        *horizontal
+                    } else {
+                        1.0 - horizontal
+                    }
+                },
+                KruskalWeights::Cells(cells) => {
+                    let weight = |c: UVec2| cells.get((c.y * maze.size.x + c.x) as usize).copied().unwrap_or(1.0);
+                    0.5 * (weight(a) + weight(b))
+                },
+                KruskalWeights::Noise { .. } => noise.as_ref().unwrap().sample(0.5 * (a + b + 1).as_vec2()),
+            };
+
+            let u: f32 = self.rng.random();
+            keys.push(if weight > 0.0 { u.powf(weight.recip()) } else { 0.0 });
         }
+
+        let mut order: Vec<_> = (0..self.walls.len()).collect();
+        order.sort_by(|&i, &j| keys[i].total_cmp(&keys[j]));
+        self.walls = order.into_iter().map(|i| self.walls[i]).collect();
     }
 }
 
 impl Algorithm for Kruskal {
     fn initialize(&mut self, maze: &mut MazeState) {
-        self.rng = SmallRng::from_rng(&mut rand::rng());
+        self.rng = SmallRng::seed_from_u64(self.seed);
 
         self.wall_set = (0..maze.walls()).collect();
         self.walls = (0..maze.walls()).collect();
-        self.walls.shuffle(&mut self.rng);
-
-        self.parent.clear();
-        self.rank.clear();
-        self.parent.reserve((maze.size.x * maze.size.y) as usize);
-        self.rank.reserve((maze.size.x * maze.size.y) as usize);
 
-        for y in 0..maze.size.y {
-            for x in 0..maze.size.x {
-                self.parent.push((y * maze.size.x + x) as usize);
-                self.rank.push(0);
-            }
+        if self.weights == KruskalWeights::Uniform {
+            self.walls.shuffle(&mut self.rng);
+        } else {
+            self.sort_walls(maze);
         }
 
-        maze.wall_head = self.walls.pop().unwrap();
-        self.wall_set.remove(&maze.wall_head);
+        self.sets = DisjointSets::new((maze.size.x * maze.size.y) as usize);
+
+        // A single cell has no walls to consider.
+        let Some(wall) = self.walls.pop() else {
+            maze.finish();
+            return;
+        };
+
+        maze.wall_head = wall;
+        self.wall_set.remove(&wall);
     }
 
     fn step(&mut self, maze: &mut MazeState) {
-        let (a, b) = if maze.wall_head >= (maze.size.x - 1) * maze.size.y {
-            // Vertical wall
-            let pos = maze.wall_head - (maze.size.x - 1) * maze.size.y;
-            let pos = UVec2::new(pos % maze.size.x, pos / maze.size.x);
-            (pos, pos + UVec2::Y)
-        } else {
-            // Horizontal wall
-            let pos = UVec2::new(maze.wall_head % (maze.size.x - 1), maze.wall_head / (maze.size.x - 1));
-            (pos, pos + UVec2::X)
-        };
+        let (a, b) = Kruskal::wall_cells(maze, maze.wall_head);
 
-        let u = self.find_parent((a.y * maze.size.x + a.x) as usize) as u32;
-        let v = self.find_parent((b.y * maze.size.x + b.x) as usize) as u32;
+        let u = (a.y * maze.size.x + a.x) as usize;
+        let v = (b.y * maze.size.x + b.x) as usize;
 
         let (mut changed_a, mut changed_b) = (false, false);
 
-        if u != v {
+        if self.sets.union(u, v) {
             maze.neighbors.open(a, Direction::from_offset(a, b));
-            self.union(u as usize, v as usize);
 
             if !maze.visited(a) {
                 changed_a = true;
@@ -1,20 +1,38 @@
-use crate::maze::MazeState;
+use std::str::FromStr;
 
+use glam::UVec2;
+
+use crate::{direction::Corner, maze::MazeState};
+
+mod aldous_broder;
+mod binary_tree;
+mod blobby_division;
+mod cellular_automaton;
 mod dfs;
 mod eller;
 mod growing_tree;
+mod hunt_and_kill;
 mod kruskal;
+mod origin_shift;
 mod prim;
 mod recursive_division;
 mod sidewinder;
+mod wilson;
 
+pub use aldous_broder::AldousBroder;
+pub use binary_tree::BinaryTree;
+pub use blobby_division::BlobbyDivision;
+pub use cellular_automaton::{CellularAutomaton, LifeRule};
 pub use dfs::DepthFirstSearch;
 pub use eller::Eller;
-pub use growing_tree::GrowingTree;
-pub use kruskal::Kruskal;
+pub use growing_tree::{GrowingTree, GrowingTreePick, GrowingTreeStrategy};
+pub use hunt_and_kill::{HuntAndKill, HuntOrder};
+pub use kruskal::{Kruskal, KruskalWeights};
+pub use origin_shift::OriginShift;
 pub use prim::Prim;
 pub use recursive_division::RecursiveDivision;
 pub use sidewinder::Sidewinder;
+pub use wilson::Wilson;
 
 pub trait Algorithm {
     fn initialize(&mut self, maze: &mut MazeState);
@@ -31,6 +49,107 @@ pub enum AlgorithmLabel {
     Eller,
     Sidewinder,
     RecursiveDivision,
+    Wilson,
+    AldousBroder,
+    HuntAndKill,
+    BinaryTree,
+    BlobbyDivision,
+    OriginShift,
+    CellularAutomaton,
+}
+
+impl AlgorithmLabel {
+    pub const ALL: &'static [AlgorithmLabel] = &[
+        AlgorithmLabel::DepthFirstSearch,
+        AlgorithmLabel::Prim,
+        AlgorithmLabel::GrowingTree,
+        AlgorithmLabel::Kruskal,
+        AlgorithmLabel::Eller,
+        AlgorithmLabel::Sidewinder,
+        AlgorithmLabel::RecursiveDivision,
+        AlgorithmLabel::Wilson,
+        AlgorithmLabel::AldousBroder,
+        AlgorithmLabel::HuntAndKill,
+        AlgorithmLabel::BinaryTree,
+        AlgorithmLabel::BlobbyDivision,
+        AlgorithmLabel::OriginShift,
+        AlgorithmLabel::CellularAutomaton,
+    ];
+
+    /// A short, lowercase name suitable for command lines and file names.
+    pub fn name(self) -> &'static str {
+        match self {
+            AlgorithmLabel::DepthFirstSearch => "dfs",
+            AlgorithmLabel::Prim => "prim",
+            AlgorithmLabel::GrowingTree => "growing-tree",
+            AlgorithmLabel::Kruskal => "kruskal",
+            AlgorithmLabel::Eller => "eller",
+            AlgorithmLabel::Sidewinder => "sidewinder",
+            AlgorithmLabel::RecursiveDivision => "recursive-division",
+            AlgorithmLabel::Wilson => "wilson",
+            AlgorithmLabel::AldousBroder => "aldous-broder",
+            AlgorithmLabel::HuntAndKill => "hunt-and-kill",
+            AlgorithmLabel::BinaryTree => "binary-tree",
+            AlgorithmLabel::BlobbyDivision => "blobby-division",
+            AlgorithmLabel::OriginShift => "origin-shift",
+            AlgorithmLabel::CellularAutomaton => "cellular-automaton",
+        }
+    }
+}
+
+impl FromStr for AlgorithmLabel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AlgorithmLabel::ALL
+            .iter()
+            .copied()
+            .find(|label| label.name() == s)
+            .ok_or_else(|| format!("unknown algorithm `{s}`"))
+    }
+}
+
+/// Parameters for the algorithms that take them. Algorithms ignore the options that do not apply to them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlgorithmOptions {
+    /// How [`GrowingTree`] picks the cell to grow from.
+    pub strategy: GrowingTreeStrategy,
+    /// How [`Kruskal`] orders the walls it considers.
+    pub weights: KruskalWeights,
+    /// Fraction of visited cells after which [`AldousBroder`] switches to Wilson's algorithm.
+    pub hybrid_threshold: Option<f32>,
+    /// The order in which [`HuntAndKill`] looks for a new cell once it gets stuck.
+    pub hunt_order: HuntOrder,
+    /// The corner [`BinaryTree`] carves towards.
+    pub bias: Corner,
+    /// Regions [`RecursiveDivision`] leaves open instead of dividing them further.
+    pub room_size: UVec2,
+    /// Chance of [`RecursiveDivision`] dividing with a horizontal wall, instead of always dividing along the shorter
+    /// side.
+    pub split_bias: Option<f32>,
+    /// Number of openings [`RecursiveDivision`] leaves in each wall.
+    pub gaps: u32,
+    /// Number of shifts after which [`OriginShift`] finishes, or `None` to keep going forever.
+    pub shift_budget: Option<u32>,
+    /// The rule [`CellularAutomaton`] runs.
+    pub rule: LifeRule,
+}
+
+impl Default for AlgorithmOptions {
+    fn default() -> Self {
+        AlgorithmOptions {
+            strategy: GrowingTreeStrategy::default(),
+            weights: KruskalWeights::default(),
+            hybrid_threshold: None,
+            hunt_order: HuntOrder::default(),
+            bias: Corner::default(),
+            room_size: UVec2::ZERO,
+            split_bias: None,
+            gaps: 1,
+            shift_budget: None,
+            rule: LifeRule::default(),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -42,18 +161,42 @@ pub enum MazeAlgorithm {
     Eller(Eller),
     Sidewinder(Sidewinder),
     RecursiveDivision(RecursiveDivision),
+    Wilson(Wilson),
+    AldousBroder(AldousBroder),
+    HuntAndKill(HuntAndKill),
+    BinaryTree(BinaryTree),
+    BlobbyDivision(BlobbyDivision),
+    OriginShift(OriginShift),
+    CellularAutomaton(CellularAutomaton),
 }
 
 impl MazeAlgorithm {
-    pub fn from_label(label: AlgorithmLabel) -> MazeAlgorithm {
+    /// Creates the algorithm for `label`. The same `seed`, label, options and maze size always produce the same maze.
+    pub fn from_label(label: AlgorithmLabel, seed: u64, options: &AlgorithmOptions) -> MazeAlgorithm {
         match label {
-            AlgorithmLabel::DepthFirstSearch => MazeAlgorithm::DepthFirstSearch(DepthFirstSearch::new()),
-            AlgorithmLabel::Prim => MazeAlgorithm::Prim(Prim::new()),
-            AlgorithmLabel::GrowingTree => MazeAlgorithm::GrowingTree(GrowingTree::new()),
-            AlgorithmLabel::Kruskal => MazeAlgorithm::Kruskal(Kruskal::new()),
-            AlgorithmLabel::Eller => MazeAlgorithm::Eller(Eller::new()),
-            AlgorithmLabel::Sidewinder => MazeAlgorithm::Sidewinder(Sidewinder::new()),
-            AlgorithmLabel::RecursiveDivision => MazeAlgorithm::RecursiveDivision(RecursiveDivision::new()),
+            AlgorithmLabel::DepthFirstSearch => MazeAlgorithm::DepthFirstSearch(DepthFirstSearch::new(seed)),
+            AlgorithmLabel::Prim => MazeAlgorithm::Prim(Prim::new(seed)),
+            AlgorithmLabel::GrowingTree => MazeAlgorithm::GrowingTree(GrowingTree::new(seed, options.strategy)),
+            AlgorithmLabel::Kruskal => MazeAlgorithm::Kruskal(Kruskal::new(seed, options.weights.clone())),
+            AlgorithmLabel::Eller => MazeAlgorithm::Eller(Eller::new(seed)),
+            AlgorithmLabel::Sidewinder => MazeAlgorithm::Sidewinder(Sidewinder::new(seed)),
+            AlgorithmLabel::RecursiveDivision => MazeAlgorithm::RecursiveDivision(RecursiveDivision::new(
+                seed,
+                options.room_size,
+                options.split_bias,
+                options.gaps,
+            )),
+            AlgorithmLabel::Wilson => MazeAlgorithm::Wilson(Wilson::new(seed)),
+            AlgorithmLabel::AldousBroder => {
+                MazeAlgorithm::AldousBroder(AldousBroder::new(seed, options.hybrid_threshold))
+            },
+            AlgorithmLabel::HuntAndKill => MazeAlgorithm::HuntAndKill(HuntAndKill::new(seed, options.hunt_order)),
+            AlgorithmLabel::BinaryTree => MazeAlgorithm::BinaryTree(BinaryTree::new(seed, options.bias)),
+            AlgorithmLabel::BlobbyDivision => MazeAlgorithm::BlobbyDivision(BlobbyDivision::new(seed)),
+            AlgorithmLabel::OriginShift => MazeAlgorithm::OriginShift(OriginShift::new(seed, options.shift_budget)),
+            AlgorithmLabel::CellularAutomaton => {
+                MazeAlgorithm::CellularAutomaton(CellularAutomaton::new(seed, options.rule))
+            },
         }
     }
 }
@@ -68,6 +211,13 @@ impl Algorithm for MazeAlgorithm {
             MazeAlgorithm::Eller(a) => a.initialize(maze),
             MazeAlgorithm::Sidewinder(a) => a.initialize(maze),
             MazeAlgorithm::RecursiveDivision(a) => a.initialize(maze),
+            MazeAlgorithm::Wilson(a) => a.initialize(maze),
+            MazeAlgorithm::AldousBroder(a) => a.initialize(maze),
+            MazeAlgorithm::HuntAndKill(a) => a.initialize(maze),
+            MazeAlgorithm::BinaryTree(a) => a.initialize(maze),
+            MazeAlgorithm::BlobbyDivision(a) => a.initialize(maze),
+            MazeAlgorithm::OriginShift(a) => a.initialize(maze),
+            MazeAlgorithm::CellularAutomaton(a) => a.initialize(maze),
         }
     }
 
@@ -80,6 +230,13 @@ impl Algorithm for MazeAlgorithm {
             MazeAlgorithm::Eller(a) => a.step(maze),
             MazeAlgorithm::Sidewinder(a) => a.step(maze),
             MazeAlgorithm::RecursiveDivision(a) => a.step(maze),
+            MazeAlgorithm::Wilson(a) => a.step(maze),
+            MazeAlgorithm::AldousBroder(a) => a.step(maze),
+            MazeAlgorithm::HuntAndKill(a) => a.step(maze),
+            MazeAlgorithm::BinaryTree(a) => a.step(maze),
+            MazeAlgorithm::BlobbyDivision(a) => a.step(maze),
+            MazeAlgorithm::OriginShift(a) => a.step(maze),
+            MazeAlgorithm::CellularAutomaton(a) => a.step(maze),
         }
     }
 }
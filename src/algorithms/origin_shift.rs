@@ -0,0 +1,97 @@
+use glam::UVec2;
+use rand::{rngs::SmallRng, SeedableRng};
+
+use crate::{direction::Direction, maze::MazeState};
+
+use super::Algorithm;
+
+#[derive(Debug, Clone)]
+pub struct OriginShift {
+    seed: u64,
+    rng: SmallRng,
+    budget: Option<u32>,
+    steps: u32,
+    parents: Vec<Option<Direction>>,
+}
+
+impl OriginShift {
+    /// Creates the generator. It keeps reshaping the maze forever, unless a `budget` limits the number of shifts.
+    pub fn new(seed: u64, budget: Option<u32>) -> OriginShift {
+        OriginShift {
+            seed,
+            rng: SmallRng::seed_from_u64(seed),
+            budget,
+            steps: 0,
+            parents: Vec::new(),
+        }
+    }
+
+    /// A number of shifts after which the maze no longer resembles the initial one.
+    pub fn mixing_steps(size: UVec2) -> u32 {
+        10 * size.x * size.y
+    }
+
+    fn parent(&mut self, maze: &MazeState, cell: UVec2) -> &mut Option<Direction> {
+        &mut self.parents[(cell.y * maze.size.x + cell.x) as usize]
+    }
+}
+
+impl Algorithm for OriginShift {
+    fn initialize(&mut self, maze: &mut MazeState) {
+        self.rng = SmallRng::seed_from_u64(self.seed);
+        self.steps = 0;
+        self.parents.clear();
+
+        // Start with every row leading east into the last column, which leads north to the origin.
+        for y in 0..maze.size.y {
+            for x in 0..maze.size.x {
+                let cell = UVec2::new(x, y);
+                let edges = maze.edges(cell);
+
+                let parent = if !edges.east {
+                    Some(Direction::East)
+                } else if !edges.north {
+                    Some(Direction::North)
+                } else {
+                    None
+                };
+
+                if let Some(dir) = parent {
+                    maze.neighbors.open(cell, dir);
+                }
+
+                self.parents.push(parent);
+                maze.set_visited(cell);
+                maze.set_finalized(cell);
+            }
+        }
+
+        maze.head = maze.size - 1;
+    }
+
+    fn step(&mut self, maze: &mut MazeState) {
+        if self.budget.is_some_and(|budget| self.steps >= budget) {
+            maze.finish();
+            return;
+        }
+
+        self.steps += 1;
+
+        let origin = maze.head;
+        let Some(dir) = (!maze.edges(origin)).choose(&mut self.rng) else {
+            return;
+        };
+        let next = dir.offset(origin);
+
+        // The new origin drops the link to its parent, and the old origin links to the new one instead.
+        if let Some(old) = self.parent(maze, next).take() {
+            maze.neighbors.close(next, old);
+            maze.wall_head = maze.wall_index(next, old);
+        }
+
+        *self.parent(maze, origin) = Some(dir);
+        maze.neighbors.open(origin, dir);
+
+        maze.head = next;
+    }
+}
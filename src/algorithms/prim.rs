@@ -8,14 +8,16 @@ use super::Algorithm;
 
 #[derive(Debug, Clone)]
 pub struct Prim {
+    seed: u64,
     rng: SmallRng,
     visited: IndexSet<UVec2>,
 }
 
 impl Prim {
-    pub fn new() -> Prim {
+    pub fn new(seed: u64) -> Prim {
         Prim {
-            rng: SmallRng::seed_from_u64(0),
+            seed,
+            rng: SmallRng::seed_from_u64(seed),
             visited: IndexSet::new(),
         }
     }
@@ -23,7 +25,7 @@ impl Prim {
 
 impl Algorithm for Prim {
     fn initialize(&mut self, maze: &mut MazeState) {
-        self.rng = SmallRng::from_rng(&mut rand::rng());
+        self.rng = SmallRng::seed_from_u64(self.seed);
         self.visited.clear();
 
         let x = self.rng.random_range(0..maze.size.x);
@@ -1,5 +1,5 @@
 use glam::{UVec2, UVec4};
-use rand::{rngs::SmallRng, Rng, SeedableRng};
+use rand::{rngs::SmallRng, seq::index, Rng, SeedableRng};
 
 use crate::{direction::Direction, maze::MazeState};
 
@@ -7,15 +7,26 @@ use super::Algorithm;
 
 #[derive(Debug, Clone)]
 pub struct RecursiveDivision {
+    seed: u64,
     rng: SmallRng,
+    room_size: UVec2,
+    split_bias: Option<f32>,
+    gaps: u32,
     first: Vec<UVec4>,
     second: Vec<UVec4>,
 }
 
 impl RecursiveDivision {
-    pub fn new() -> RecursiveDivision {
+    /// Creates the generator. Regions no larger than `room_size` are left open as rooms, `split_bias` is the chance
+    /// of dividing a region with a horizontal wall instead of along its shorter side, and every wall gets `gaps`
+    /// openings.
+    pub fn new(seed: u64, room_size: UVec2, split_bias: Option<f32>, gaps: u32) -> RecursiveDivision {
         RecursiveDivision {
-            rng: SmallRng::seed_from_u64(0),
+            seed,
+            rng: SmallRng::seed_from_u64(seed),
+            room_size,
+            split_bias,
+            gaps: gaps.max(1),
             first: Vec::new(),
             second: Vec::new(),
         }
@@ -24,7 +35,7 @@ impl RecursiveDivision {
 
 impl Algorithm for RecursiveDivision {
     fn initialize(&mut self, maze: &mut MazeState) {
-        self.rng = SmallRng::from_rng(&mut rand::rng());
+        self.rng = SmallRng::seed_from_u64(self.seed);
         self.first.clear();
         self.first.push(UVec4::new(0, 0, maze.size.x, maze.size.y));
 
@@ -56,7 +67,7 @@ impl Algorithm for RecursiveDivision {
             }
         }
 
-        if width < 2 || height < 2 {
+        if width < 2 || height < 2 || width <= self.room_size.x && height <= self.room_size.y {
             for i in x..x + width {
                 for j in y..y + height {
                     maze.set_finalized(UVec2::new(i, j));
@@ -66,10 +77,14 @@ impl Algorithm for RecursiveDivision {
             return;
         }
 
-        let horizontal = match width.cmp(&height) {
-            std::cmp::Ordering::Less => true,
-            std::cmp::Ordering::Equal => self.rng.random_bool(0.5),
-            std::cmp::Ordering::Greater => false,
+        let horizontal = if let Some(bias) = self.split_bias {
+            self.rng.random_bool(bias.clamp(0.0, 1.0) as f64)
+        } else {
+            match width.cmp(&height) {
+                std::cmp::Ordering::Less => true,
+                std::cmp::Ordering::Equal => self.rng.random_bool(0.5),
+                std::cmp::Ordering::Greater => false,
+            }
         };
 
         let length = if horizontal { width } else { height };
@@ -93,14 +108,16 @@ impl Algorithm for RecursiveDivision {
             },
         );
 
-        let p = UVec2::new(
-            w.x + if horizontal { self.rng.random_range(0..width) } else { 0 },
-            w.y + if horizontal {
-                0
-            } else {
-                self.rng.random_range(0..height)
-            },
-        );
+        let gaps = if self.gaps == 1 {
+            vec![self.rng.random_range(0..length)]
+        } else {
+            index::sample(&mut self.rng, length as usize, self.gaps.min(length) as usize)
+                .into_iter()
+                .map(|i| i as u32)
+                .collect()
+        };
+
+        let p = w + delta * gaps[0];
 
         if horizontal {
             maze.wall_head = p.y * maze.size.x + p.x + (maze.size.x - 1) * maze.size.y;
@@ -108,8 +125,8 @@ impl Algorithm for RecursiveDivision {
             maze.wall_head = p.y * (maze.size.x - 1) + p.x;
         }
 
-        for _ in 0..length {
-            if w != p {
+        for i in 0..length {
+            if !gaps.contains(&i) {
                 maze.neighbors.close(w, dir);
             }
 
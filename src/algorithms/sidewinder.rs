@@ -1,7 +1,5 @@
-use std::collections::HashSet;
-
 use glam::UVec2;
-use rand::{rngs::SmallRng, seq::IteratorRandom, Rng, SeedableRng};
+use rand::{rngs::SmallRng, seq::IndexedRandom, Rng, SeedableRng};
 
 use crate::{direction::Direction, maze::MazeState};
 
@@ -9,16 +7,18 @@ use super::Algorithm;
 
 #[derive(Debug, Clone)]
 pub struct Sidewinder {
+    seed: u64,
     rng: SmallRng,
-    run: HashSet<UVec2>,
+    run: Vec<UVec2>,
     finalizing: bool,
 }
 
 impl Sidewinder {
-    pub fn new() -> Sidewinder {
+    pub fn new(seed: u64) -> Sidewinder {
         Sidewinder {
-            rng: SmallRng::seed_from_u64(0),
-            run: HashSet::new(),
+            seed,
+            rng: SmallRng::seed_from_u64(seed),
+            run: Vec::new(),
             finalizing: false,
         }
     }
@@ -26,7 +26,7 @@ impl Sidewinder {
 
 impl Algorithm for Sidewinder {
     fn initialize(&mut self, maze: &mut MazeState) {
-        self.rng = SmallRng::from_rng(&mut rand::rng());
+        self.rng = SmallRng::seed_from_u64(self.seed);
         self.run.clear();
         self.finalizing = false;
         maze.head = UVec2::ZERO;
@@ -49,12 +49,12 @@ impl Algorithm for Sidewinder {
                     maze.neighbors.open(maze.head, Direction::East);
                 }
             } else {
-                self.run.insert(maze.head);
+                self.run.push(maze.head);
 
                 if self.rng.random_bool(2.0 / 3.0) && !edges.east {
                     maze.neighbors.open(maze.head, Direction::East);
                 } else {
-                    let cell = self.run.iter().choose(&mut self.rng).unwrap();
+                    let cell = self.run.choose(&mut self.rng).unwrap();
                     maze.neighbors.open(*cell, Direction::South);
                     self.run.clear();
                 }
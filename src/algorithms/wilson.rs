@@ -0,0 +1,99 @@
+use glam::UVec2;
+use indexmap::IndexSet;
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+use crate::{direction::Direction, maze::MazeState};
+
+use super::Algorithm;
+
+#[derive(Debug, Clone)]
+pub struct Wilson {
+    seed: u64,
+    rng: SmallRng,
+    remaining: IndexSet<UVec2>,
+    walk: IndexSet<UVec2>,
+}
+
+impl Wilson {
+    pub fn new(seed: u64) -> Wilson {
+        Wilson {
+            seed,
+            rng: SmallRng::seed_from_u64(seed),
+            remaining: IndexSet::new(),
+            walk: IndexSet::new(),
+        }
+    }
+
+    /// Continues from a partially built maze, treating every finalized cell as part of the tree.
+    pub(super) fn resume(&mut self, maze: &MazeState) {
+        self.walk.clear();
+        self.remaining.clear();
+
+        for y in 0..maze.size.y {
+            for x in 0..maze.size.x {
+                let cell = UVec2::new(x, y);
+
+                if !maze.finalized(cell) {
+                    self.remaining.insert(cell);
+                }
+            }
+        }
+    }
+}
+
+impl Algorithm for Wilson {
+    fn initialize(&mut self, maze: &mut MazeState) {
+        self.rng = SmallRng::seed_from_u64(self.seed);
+        self.resume(maze);
+
+        let index = self.rng.random_range(0..self.remaining.len());
+        let root = self.remaining.swap_remove_index(index).unwrap();
+
+        maze.head = root;
+        maze.set_visited(root);
+        maze.set_finalized(root);
+    }
+
+    fn step(&mut self, maze: &mut MazeState) {
+        if self.walk.is_empty() {
+            if self.remaining.is_empty() {
+                maze.finish();
+                return;
+            }
+
+            let index = self.rng.random_range(0..self.remaining.len());
+            maze.head = *self.remaining.get_index(index).unwrap();
+
+            self.walk.insert(maze.head);
+            maze.set_visited(maze.head);
+            return;
+        }
+
+        let Some(dir) = (!maze.edges(maze.head)).choose(&mut self.rng) else {
+            return;
+        };
+        let next = dir.offset(maze.head);
+
+        if maze.finalized(next) {
+            // The walk reached the tree, so carve it into the maze.
+            let mut previous = next;
+
+            while let Some(cell) = self.walk.pop() {
+                maze.neighbors.open(cell, Direction::from_offset(cell, previous));
+                maze.set_finalized(cell);
+                self.remaining.swap_remove(&cell);
+                previous = cell;
+            }
+        } else if let Some(index) = self.walk.get_index_of(&next) {
+            // The walk ran into itself, so erase the loop it just made.
+            for cell in self.walk.drain(index + 1..) {
+                maze.unset_visited(cell);
+            }
+        } else {
+            self.walk.insert(next);
+            maze.set_visited(next);
+        }
+
+        maze.head = next;
+    }
+}
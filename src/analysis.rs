@@ -0,0 +1,209 @@
+use glam::UVec2;
+
+use crate::{
+    maze::MazeState,
+    solve::{self, DistanceMap},
+};
+
+/// Corridors at least this long are counted together in the last bucket of [`MazeStats::corridor_lengths`].
+pub const MAX_CORRIDOR_LENGTH: usize = 16;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MazeStats {
+    /// Number of cells that are part of the maze, which excludes any that were culled into rock.
+    pub cells: u32,
+    /// Cells with exactly one passage.
+    pub dead_ends: u32,
+    /// Cells with exactly three passages.
+    pub junctions: u32,
+    /// Cells with four passages.
+    pub crossroads: u32,
+    /// The number of corridors of each length, where a corridor is a run of cells that each have exactly two
+    /// passages. Index zero is unused.
+    pub corridor_lengths: [u32; MAX_CORRIDOR_LENGTH + 1],
+    /// The mean number of cells in a dead-end branch, from the dead end up to the first junction. Mazes with a high
+    /// river factor flow along a few long branches, while mazes with a low one sprout many short dead ends.
+    pub river: f32,
+    /// The number of steps between the endpoints, if they are connected.
+    pub solution_length: Option<u32>,
+    /// The fraction of cells on the path between the endpoints, if they are connected.
+    pub solution_coverage: Option<f32>,
+}
+
+/// Measures a maze. The solution is taken between `endpoints`, or along the longest path through the maze if there
+/// are none.
+pub fn analyze(maze: &MazeState, endpoints: Option<(UVec2, UVec2)>) -> MazeStats {
+    let mut stats = MazeStats {
+        cells: 0,
+        dead_ends: 0,
+        junctions: 0,
+        crossroads: 0,
+        corridor_lengths: [0; MAX_CORRIDOR_LENGTH + 1],
+        river: 0.0,
+        solution_length: None,
+        solution_coverage: None,
+    };
+
+    let degree = |cell: UVec2| maze.passages(cell).into_iter().count();
+    let mut in_corridor = vec![false; (maze.size.x * maze.size.y) as usize];
+
+    for y in 0..maze.size.y {
+        for x in 0..maze.size.x {
+            let cell = UVec2::new(x, y);
+
+            if maze.excluded(cell) {
+                continue;
+            }
+
+            stats.cells += 1;
+
+            match degree(cell) {
+                1 => stats.dead_ends += 1,
+                3 => stats.junctions += 1,
+                4 => stats.crossroads += 1,
+                _ => {},
+            }
+        }
+    }
+
+    for y in 0..maze.size.y {
+        for x in 0..maze.size.x {
+            let cell = UVec2::new(x, y);
+
+            if degree(cell) != 2 || in_corridor[(y * maze.size.x + x) as usize] {
+                continue;
+            }
+
+            let mut length = 0;
+            let mut stack = vec![cell];
+            in_corridor[(y * maze.size.x + x) as usize] = true;
+
+            while let Some(c) = stack.pop() {
+                length += 1;
+
+                for dir in maze.passages(c) {
+                    let next = dir.offset(c);
+                    let index = (next.y * maze.size.x + next.x) as usize;
+
+                    if degree(next) == 2 && !in_corridor[index] {
+                        in_corridor[index] = true;
+                        stack.push(next);
+                    }
+                }
+            }
+
+            stats.corridor_lengths[length.min(MAX_CORRIDOR_LENGTH)] += 1;
+        }
+    }
+
+    let mut branch_cells = 0;
+
+    for y in 0..maze.size.y {
+        for x in 0..maze.size.x {
+            let cell = UVec2::new(x, y);
+
+            if maze.excluded(cell) || degree(cell) != 1 {
+                continue;
+            }
+
+            // Follow the corridor away from the dead end until it reaches a junction or another dead end.
+            let (mut prev, mut current) = (cell, cell);
+
+            loop {
+                branch_cells += 1;
+
+                let mut next = maze.passages(current).into_iter().map(|d| d.offset(current));
+                match next.find(|&n| n != prev) {
+                    Some(n) if degree(n) == 2 => (prev, current) = (current, n),
+                    _ => break,
+                }
+            }
+        }
+    }
+
+    if stats.dead_ends > 0 {
+        stats.river = branch_cells as f32 / stats.dead_ends as f32;
+    }
+
+    if let Some((start, goal)) = endpoints.or_else(|| solve::diameter(maze)) {
+        if let Some(length) = DistanceMap::new(maze, start).get(goal) {
+            stats.solution_length = Some(length);
+            stats.solution_coverage = Some((length + 1) as f32 / stats.cells.max(1) as f32);
+        }
+    }
+
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::direction::Direction;
+
+    use super::*;
+
+    /// A 3x3 tree with two junctions on the middle column, a dead end on each side of the bottom junction, and a
+    /// two-cell branch on each side of the top one:
+    ///
+    /// ```text
+    /// +---+---+---+
+    /// |           |
+    /// +   +   +   +
+    /// |   |   |   |
+    /// +---+   +---+
+    /// |           |
+    /// +---+---+---+
+    /// ```
+    fn tree() -> MazeState {
+        MazeState::from_passages(
+            UVec2::new(3, 3),
+            &[
+                (UVec2::new(0, 0), Direction::East),
+                (UVec2::new(1, 0), Direction::East),
+                (UVec2::new(1, 0), Direction::North),
+                (UVec2::new(1, 1), Direction::North),
+                (UVec2::new(0, 1), Direction::North),
+                (UVec2::new(0, 2), Direction::East),
+                (UVec2::new(1, 2), Direction::East),
+                (UVec2::new(2, 2), Direction::South),
+            ],
+        )
+    }
+
+    #[test]
+    fn counts_cells_by_passages() {
+        let stats = analyze(&tree(), None);
+
+        assert_eq!(stats.cells, 9);
+        assert_eq!(stats.dead_ends, 4);
+        assert_eq!(stats.junctions, 2);
+        assert_eq!(stats.crossroads, 0);
+
+        let mut corridors = [0; MAX_CORRIDOR_LENGTH + 1];
+        corridors[1] = 3;
+        assert_eq!(stats.corridor_lengths, corridors);
+
+        // Two dead ends lead straight into a junction, and the other two go through a corridor cell first.
+        assert_eq!(stats.river, 1.5);
+    }
+
+    #[test]
+    fn measures_solution() {
+        let stats = analyze(&tree(), Some((UVec2::new(0, 0), UVec2::new(2, 1))));
+        assert_eq!(stats.solution_length, Some(5));
+        assert_eq!(stats.solution_coverage, Some(6.0 / 9.0));
+
+        // Without endpoints, the solution runs along the longest path.
+        let stats = analyze(&tree(), None);
+        assert_eq!(stats.solution_length, Some(5));
+    }
+
+    #[test]
+    fn unreachable_goal_has_no_solution() {
+        let stats = analyze(&MazeState::new(UVec2::new(2, 1)), Some((UVec2::ZERO, UVec2::X)));
+
+        assert_eq!(stats.dead_ends, 0);
+        assert_eq!(stats.river, 0.0);
+        assert_eq!(stats.solution_length, None);
+        assert_eq!(stats.solution_coverage, None);
+    }
+}
@@ -29,7 +29,7 @@ pub fn astar(start: UVec2, goal: UVec2, maze: &MazeState) -> Option<(HashSet<UVe
                 continue;
             }
 
-            maze.neighbors[node].into_iter().map(move |dir| dir.offset(node))
+            maze.passages(node).into_iter().map(move |dir| dir.offset(node))
         };
 
         for successor in successors {
@@ -0,0 +1,316 @@
+use std::{collections::HashSet, fmt::Write as _, fs, path::PathBuf, process::ExitCode, str::FromStr};
+
+use glam::UVec2;
+use maze_algorithms::{
+    algorithms::AlgorithmLabel,
+    direction::Direction,
+    maze::MazeState,
+    solve::{DijkstraWeights, MazeSolver, Solver, SolverLabel, SolverOptions},
+};
+
+const USAGE: &str = "\
+Usage: maze-cli [OPTIONS]
+
+Options:
+  --algorithm <NAME>   Generator to use [default: recursive-division]
+  --size <WxH>         Maze size, at least 1x1 [default: 16x16]
+  --seed <N>           Seed of the first maze, incremented for each following one [default: random]
+  --count <N>          Number of mazes to generate [default: 1]
+  --out <DIR>          Directory to write the mazes to [default: .]
+  --start <CORNER>     Corner to solve from: sw, se, nw or ne [default: sw]
+  --goal <CORNER>      Corner to solve to: sw, se, nw or ne [default: ne]
+  --solver <NAME>      Solver to find the path with [default: astar]
+  --weights <SPEC>     Cell weights for dijkstra: uniform, or noise:SCALE:MAX [default: uniform]
+  -h, --help           Print this message";
+
+#[derive(Debug, Clone, Copy)]
+enum Corner {
+    SouthWest,
+    SouthEast,
+    NorthWest,
+    NorthEast,
+}
+
+impl Corner {
+    fn cell(self, size: UVec2) -> UVec2 {
+        match self {
+            Corner::SouthWest => UVec2::ZERO,
+            Corner::SouthEast => UVec2::new(size.x - 1, 0),
+            Corner::NorthWest => UVec2::new(0, size.y - 1),
+            Corner::NorthEast => size - 1,
+        }
+    }
+}
+
+impl FromStr for Corner {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sw" => Ok(Corner::SouthWest),
+            "se" => Ok(Corner::SouthEast),
+            "nw" => Ok(Corner::NorthWest),
+            "ne" => Ok(Corner::NorthEast),
+            _ => Err(format!("unknown corner `{s}`, expected one of sw, se, nw, ne")),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Args {
+    algorithm: AlgorithmLabel,
+    size: UVec2,
+    seed: u64,
+    count: u64,
+    out: PathBuf,
+    start: Corner,
+    goal: Corner,
+    solver: SolverLabel,
+    weights: DijkstraWeights,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Option<Args>, String> {
+        let mut parsed = Args {
+            algorithm: AlgorithmLabel::RecursiveDivision,
+            size: UVec2::splat(16),
+            seed: rand::random(),
+            count: 1,
+            out: PathBuf::from("."),
+            start: Corner::SouthWest,
+            goal: Corner::NorthEast,
+            solver: SolverLabel::AStar,
+            weights: DijkstraWeights::Uniform,
+        };
+
+        while let Some(arg) = args.next() {
+            if arg == "-h" || arg == "--help" {
+                return Ok(None);
+            }
+
+            let value = args.next().ok_or_else(|| format!("missing value for `{arg}`"))?;
+
+            match arg.as_str() {
+                "--algorithm" => parsed.algorithm = value.parse()?,
+                "--size" => parsed.size = parse_size(&value)?,
+                "--seed" => parsed.seed = parse_number(&arg, &value)?,
+                "--count" => parsed.count = parse_number(&arg, &value)?,
+                "--out" => parsed.out = PathBuf::from(value),
+                "--start" => parsed.start = value.parse()?,
+                "--goal" => parsed.goal = value.parse()?,
+                "--solver" => parsed.solver = value.parse()?,
+                "--weights" => parsed.weights = parse_weights(&value)?,
+                _ => return Err(format!("unexpected argument `{arg}`")),
+            }
+        }
+
+        Ok(Some(parsed))
+    }
+}
+
+fn parse_number(arg: &str, value: &str) -> Result<u64, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value `{value}` for `{arg}`, expected a non-negative integer"))
+}
+
+fn parse_size(value: &str) -> Result<UVec2, String> {
+    let invalid = || format!("invalid size `{value}`, expected WIDTHxHEIGHT");
+
+    let (width, height) = value.split_once('x').ok_or_else(invalid)?;
+    let size = UVec2::new(
+        width.parse().map_err(|_| invalid())?,
+        height.parse().map_err(|_| invalid())?,
+    );
+
+    if size.x == 0 || size.y == 0 {
+        return Err(format!("invalid size `{value}`, both dimensions must be at least 1"));
+    }
+
+    // Cells and walls are indexed with `u32`, so both counts have to fit in one.
+    let cells = size.x.checked_mul(size.y);
+    let walls = (size.x - 1)
+        .checked_mul(size.y)
+        .zip(size.x.checked_mul(size.y - 1))
+        .and_then(|(a, b)| a.checked_add(b));
+
+    if cells.is_none() || walls.is_none() {
+        return Err(format!("invalid size `{value}`, the maze would have too many cells"));
+    }
+
+    Ok(size)
+}
+
+fn parse_weights(value: &str) -> Result<DijkstraWeights, String> {
+    let invalid = || format!("invalid weights `{value}`, expected uniform or noise:SCALE:MAX");
+
+    if value == "uniform" {
+        return Ok(DijkstraWeights::Uniform);
+    }
+
+    let (scale, max) = value
+        .strip_prefix("noise:")
+        .and_then(|s| s.split_once(':'))
+        .ok_or_else(invalid)?;
+
+    Ok(DijkstraWeights::Noise {
+        scale: scale.parse().map_err(|_| invalid())?,
+        max: max.parse().map_err(|_| invalid())?,
+    })
+}
+
+/// Draws the maze as text with north at the top, marking the path with `.` and its ends with `S` and `G`.
+fn to_ascii(maze: &MazeState, path: &HashSet<UVec2>, start: UVec2, goal: UVec2) -> String {
+    let border = "#".repeat(2 * maze.size.x as usize + 1);
+    let passage = |a: UVec2, b: UVec2| {
+        if path.contains(&a) && path.contains(&b) {
+            '.'
+        } else {
+            ' '
+        }
+    };
+
+    let mut out = String::new();
+    let _ = writeln!(out, "{border}");
+
+    for y in (0..maze.size.y).rev() {
+        let mut row = String::from("#");
+        let mut below = String::from("#");
+
+        for x in 0..maze.size.x {
+            let cell = UVec2::new(x, y);
+            let neighbors = maze.neighbors[cell];
+
+            row.push(if cell == start {
+                'S'
+            } else if cell == goal {
+                'G'
+            } else if path.contains(&cell) {
+                '.'
+            } else {
+                ' '
+            });
+
+            row.push(if neighbors.contains(Direction::East) {
+                passage(cell, cell + UVec2::X)
+            } else {
+                '#'
+            });
+
+            below.push(if neighbors.contains(Direction::South) {
+                passage(cell, cell - UVec2::Y)
+            } else {
+                '#'
+            });
+            below.push('#');
+        }
+
+        let _ = writeln!(out, "{row}");
+
+        if y > 0 {
+            let _ = writeln!(out, "{below}");
+        }
+    }
+
+    let _ = writeln!(out, "{border}");
+
+    out
+}
+
+fn run(args: Args) -> Result<(), String> {
+    fs::create_dir_all(&args.out).map_err(|e| format!("failed to create `{}`: {e}", args.out.display()))?;
+
+    let start = args.start.cell(args.size);
+    let goal = args.goal.cell(args.size);
+    let mut unsolved = 0;
+
+    for i in 0..args.count {
+        let seed = args.seed.wrapping_add(i);
+        let maze = maze_algorithms::generate(args.algorithm, args.size, seed);
+        let options = SolverOptions {
+            weights: args.weights.clone(),
+        };
+        let solution = MazeSolver::from_label(args.solver, seed, &options).solve(start, goal, &maze);
+
+        let file = args.out.join(format!(
+            "{}-{}x{}-{seed}.txt",
+            args.algorithm.name(),
+            args.size.x,
+            args.size.y
+        ));
+
+        let mut contents = format!(
+            "algorithm: {}\nsize: {}x{}\nseed: {seed}\n",
+            args.algorithm.name(),
+            args.size.x,
+            args.size.y,
+        );
+
+        // An unsolvable maze is still written out, so that one bad seed does not cost the rest of the batch.
+        let path: HashSet<_> = match &solution {
+            Some(solution) => {
+                let length = solution.path.len() - 1;
+                let _ = writeln!(contents, "solution length: {length}");
+
+                // Only weighted solvers find paths whose cost differs from their length.
+                if solution.cost as usize != length {
+                    let _ = writeln!(contents, "solution cost: {}", solution.cost);
+                }
+
+                let _ = writeln!(contents, "explored: {}", solution.explored);
+                solution.path.iter().copied().collect()
+            },
+            None => {
+                unsolved += 1;
+                contents.push_str("solution: none\n");
+                HashSet::new()
+            },
+        };
+
+        contents.push('\n');
+        contents.push_str(&to_ascii(&maze, &path, start, goal));
+
+        fs::write(&file, contents).map_err(|e| format!("failed to write `{}`: {e}", file.display()))?;
+
+        match solution {
+            Some(solution) => println!(
+                "{} (solution length {}, cost {}, explored {})",
+                file.display(),
+                solution.path.len() - 1,
+                solution.cost,
+                solution.explored
+            ),
+            None => println!("{} (no path between the chosen corners)", file.display()),
+        }
+    }
+
+    if unsolved > 0 {
+        return Err(format!(
+            "{unsolved} of {} mazes have no path between the chosen corners",
+            args.count
+        ));
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        },
+        Err(e) => {
+            eprintln!("error: {e}\n\n{USAGE}");
+            return ExitCode::from(2);
+        },
+    };
+
+    if let Err(e) = run(args) {
+        eprintln!("error: {e}");
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
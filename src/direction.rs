@@ -11,7 +11,7 @@ pub struct Directions {
     pub south: bool,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Direction {
     East = 0,
     North = 1,
@@ -19,6 +19,15 @@ pub enum Direction {
     South = 3,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Corner {
+    #[default]
+    NorthEast,
+    NorthWest,
+    SouthWest,
+    SouthEast,
+}
+
 impl Directions {
     pub const NONE: Directions = Directions::new(false, false, false, false);
     pub const ALL: Directions = Directions::new(true, true, true, true);
@@ -119,6 +128,23 @@ impl Direction {
         }
     }
 
+    /// The direction a quarter turn counterclockwise from this one.
+    #[inline]
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::East => Direction::North,
+            Direction::North => Direction::West,
+            Direction::West => Direction::South,
+            Direction::South => Direction::East,
+        }
+    }
+
+    /// The direction a quarter turn clockwise from this one.
+    #[inline]
+    pub fn turn_right(self) -> Direction {
+        -self.turn_left()
+    }
+
     #[inline]
     pub fn checked_offset(self, p: UVec2) -> Option<UVec2> {
         match self {
@@ -130,6 +156,19 @@ impl Direction {
     }
 }
 
+impl Corner {
+    /// The vertical and horizontal [`Direction`] pointing towards this corner.
+    #[inline]
+    pub fn directions(self) -> (Direction, Direction) {
+        match self {
+            Corner::NorthEast => (Direction::North, Direction::East),
+            Corner::NorthWest => (Direction::North, Direction::West),
+            Corner::SouthWest => (Direction::South, Direction::West),
+            Corner::SouthEast => (Direction::South, Direction::East),
+        }
+    }
+}
+
 impl IntoIterator for Directions {
     type Item = Direction;
     type IntoIter = std::vec::IntoIter<Direction>;
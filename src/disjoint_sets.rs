@@ -0,0 +1,48 @@
+/// A union-find over the indices `0..len`, with union by rank and path halving.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct DisjointSets {
+    parents: Vec<usize>,
+    ranks: Vec<u8>,
+}
+
+impl DisjointSets {
+    pub(crate) fn new(len: usize) -> DisjointSets {
+        DisjointSets {
+            parents: (0..len).collect(),
+            ranks: vec![0; len],
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub(crate) fn find(&mut self, mut i: usize) -> usize {
+        while self.parents[i] != i {
+            self.parents[i] = self.parents[self.parents[i]];
+            i = self.parents[i];
+        }
+
+        i
+    }
+
+    /// Joins the sets of `a` and `b`, returning `false` if they were already the same set.
+    pub(crate) fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+
+        if a == b {
+            return false;
+        }
+
+        match self.ranks[a].cmp(&self.ranks[b]) {
+            std::cmp::Ordering::Less => self.parents[a] = b,
+            std::cmp::Ordering::Greater => self.parents[b] = a,
+            std::cmp::Ordering::Equal => {
+                self.parents[b] = a;
+                self.ranks[a] += 1;
+            },
+        }
+
+        true
+    }
+}
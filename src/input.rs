@@ -158,11 +158,7 @@ impl InputManager {
     pub fn process_window_event(&mut self, event: &WindowEvent) {
         match event {
             WindowEvent::Focused(false) => self.current = None,
-            WindowEvent::Focused(true) => {
-                if self.current.is_none() {
-                    self.current = Some(CurrentInput::new())
-                }
-            },
+            WindowEvent::Focused(true) if self.current.is_none() => self.current = Some(CurrentInput::new()),
             _ => {},
         }
         if let Some(current) = &mut self.current {
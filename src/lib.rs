@@ -0,0 +1,74 @@
+//! Step-by-step maze generation and solving.
+//!
+//! Every generator implements [`Algorithm`], which advances a [`MazeState`] one step at a time so that it can be
+//! animated. [`generate`] runs a generator to completion for headless use, and the [`Solver`]s in [`solve`] find
+//! paths through the result.
+
+use glam::UVec2;
+
+pub mod algorithms;
+pub mod analysis;
+pub mod astar;
+pub mod direction;
+mod disjoint_sets;
+pub mod maze;
+mod noise;
+pub mod postprocess;
+pub mod solve;
+pub mod validate;
+
+pub use algorithms::{Algorithm, AlgorithmLabel, AlgorithmOptions, MazeAlgorithm};
+pub use direction::{Direction, Directions};
+pub use glam;
+pub use maze::{MazeState, Neighbors};
+pub use postprocess::{PostProcessOptions, PostProcessor};
+pub use solve::{MazeSolver, Solution, SolveState, Solver, SolverLabel, SolverOptions};
+pub use validate::{validate, Violation};
+
+/// Generates a complete maze of the given size with the algorithm for `label`, seeded with `seed`.
+pub fn generate(label: AlgorithmLabel, size: UVec2, seed: u64) -> MazeState {
+    generate_with_options(label, size, seed, &AlgorithmOptions::default())
+}
+
+/// Like [`generate`], but with non-default [`AlgorithmOptions`].
+///
+/// [`OriginShift`](algorithms::OriginShift) never finishes on its own, so without a step budget it is stopped after
+/// [`OriginShift::mixing_steps`](algorithms::OriginShift::mixing_steps) shifts.
+pub fn generate_with_options(label: AlgorithmLabel, size: UVec2, seed: u64, options: &AlgorithmOptions) -> MazeState {
+    if label == AlgorithmLabel::OriginShift && options.shift_budget.is_none() {
+        let options = AlgorithmOptions {
+            shift_budget: Some(algorithms::OriginShift::mixing_steps(size)),
+            ..options.clone()
+        };
+
+        return generate_with_options(label, size, seed, &options);
+    }
+
+    let mut maze = MazeState::new(size);
+    let mut algorithm = MazeAlgorithm::from_label(label, seed, options);
+
+    algorithm.initialize(&mut maze);
+
+    while !maze.finished {
+        algorithm.step(&mut maze);
+    }
+
+    maze
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_maze() {
+        for &label in AlgorithmLabel::ALL {
+            for size in [UVec2::new(16, 16), UVec2::new(7, 12)] {
+                let a = generate(label, size, 42);
+                let b = generate(label, size, 42);
+
+                assert_eq!(a.neighbors, b.neighbors, "{label:?} is not deterministic at {size}");
+            }
+        }
+    }
+}
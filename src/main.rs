@@ -5,17 +5,17 @@ use std::{
     time::{Duration, Instant},
 };
 
-use algorithms::{Algorithm, AlgorithmLabel, MazeAlgorithm};
 use glam::{UVec2, Vec2};
-use maze::MazeState;
-use renderer::MazeRenderer;
+use maze_algorithms::{
+    algorithms::{Algorithm, AlgorithmLabel, AlgorithmOptions, MazeAlgorithm},
+    maze::MazeState,
+    postprocess::{PostProcessOptions, PostProcessor},
+    solve::{self, MazeSolver, SolveState, Solver, SolverLabel, SolverOptions},
+};
+use renderer::{LongestPathOptions, MazeRenderer};
 use winit::event_loop::{ControlFlow, EventLoop};
 
-mod algorithms;
-mod astar;
-mod direction;
 mod input;
-mod maze;
 mod render;
 mod renderer;
 
@@ -30,10 +30,23 @@ fn main() {
 
     let mut maze_size = START_MAZE_SIZE;
     let mut algorithm_label = START_ALGORITHM;
+    let mut seed = rand::random();
+    let mut options = AlgorithmOptions::default();
+    let mut postprocess_options = PostProcessOptions::default();
+    let mut solver_label = SolverLabel::default();
+    let mut solver_options = SolverOptions::default();
+    let mut solver = MazeSolver::from_label(solver_label, seed, &solver_options);
+    let mut longest_path = LongestPathOptions::default();
+    let mut endpoints_placed = false;
+    // The openings in the outer wall made for the last start and goal placed on the boundary.
+    let mut boundary_openings = Vec::new();
 
     let mut path = HashSet::new();
+    let mut solve: Option<SolveState> = None;
+    let mut pending_solve: Option<(UVec2, UVec2)> = None;
     let mut maze = MazeState::new(maze_size);
-    let mut algorithm = MazeAlgorithm::from_label(algorithm_label);
+    let mut algorithm = MazeAlgorithm::from_label(algorithm_label, seed, &options);
+    let mut postprocessor = PostProcessor::new(seed, &postprocess_options);
 
     algorithm.initialize(&mut maze);
 
@@ -42,21 +55,68 @@ fn main() {
         let mut frame_time = Duration::from_micros(START_FRAME_TIME_US);
 
         loop {
+            let mut update_path = false;
+
             if renderer::PAUSED.load(Ordering::Relaxed) || frame_time.checked_sub(start.elapsed()).is_some() {
                 thread::yield_now();
             } else if !maze.finished {
                 algorithm.step(&mut maze);
                 start = Instant::now();
+            } else if !postprocessor.finished() {
+                postprocessor.step(&mut maze);
+                start = Instant::now();
+            } else if let Some(state) = solve.as_mut().filter(|s| !s.finished) {
+                solver.step(state, &maze);
+                start = Instant::now();
+
+                if let Some(solution) = &state.solution {
+                    path = solution.path.iter().copied().collect();
+                    update_path |= true;
+                }
+            }
+
+            // Only look for the longest path once the maze will not change anymore.
+            let done = maze.finished && postprocessor.finished();
+            // A request made while the maze is still changing stays pending until it is done.
+            let find_longest_path = done && renderer::FIND_LONGEST_PATH.swap(false, Ordering::Relaxed);
+
+            if done && (find_longest_path || (longest_path.automatic && !endpoints_placed)) {
+                endpoints_placed = true;
+
+                for (cell, dir) in boundary_openings.drain(..) {
+                    maze.neighbors.close(cell, dir);
+                }
+
+                let endpoints = if longest_path.boundary {
+                    solve::boundary_diameter(&maze)
+                } else {
+                    solve::diameter(&maze)
+                };
+
+                if let Some((start, goal)) = endpoints {
+                    if longest_path.boundary && start != goal {
+                        for cell in [start, goal] {
+                            let outside = maze.edges(cell).into_iter().next().unwrap();
+                            maze.neighbors.open(cell, outside);
+                            boundary_openings.push((cell, outside));
+                        }
+                    }
+
+                    renderer::MAZE_ENDPOINTS.lock().unwrap().replace((start, goal));
+                    renderer::MAZE_START_GOAL.lock().unwrap().replace((start, goal));
+                }
             }
 
             let mut lock = renderer::UPDATE_LOCK.lock().unwrap();
             {
                 let mut lock = renderer::MAZE_STATE.lock().unwrap();
                 *lock = maze.clone();
+                *renderer::SOLVE_STATE.lock().unwrap() = solve.clone();
+                renderer::MAZE_DONE.store(done, Ordering::Relaxed);
             }
             *lock |= true;
 
-            let mut update_path = false;
+            let mut reset = false;
 
             if let Some(micros) = renderer::FRAME_TIME.lock().unwrap().take() {
                 frame_time = Duration::from_micros(micros);
@@ -64,34 +124,88 @@ fn main() {
 
             if let Some(size) = renderer::MAZE_SIZE.lock().unwrap().take() {
                 maze_size = size;
-
-                maze = MazeState::new(maze_size);
-                algorithm = MazeAlgorithm::from_label(algorithm_label);
-                algorithm.initialize(&mut maze);
-                path.clear();
-                update_path |= true;
+                reset |= true;
             }
 
             if let Some(label) = renderer::MAZE_ALGORITHM.lock().unwrap().take() {
                 algorithm_label = label;
+                reset |= true;
+            }
 
+            if let Some(s) = renderer::MAZE_SEED.lock().unwrap().take() {
+                seed = s;
+                reset |= true;
+            }
+
+            if let Some(o) = renderer::MAZE_OPTIONS.lock().unwrap().take() {
+                options = o;
+                reset |= true;
+            }
+
+            if let Some(o) = renderer::MAZE_POSTPROCESS.lock().unwrap().take() {
+                postprocess_options = o;
+                reset |= true;
+            }
+
+            if reset {
                 maze = MazeState::new(maze_size);
-                algorithm = MazeAlgorithm::from_label(algorithm_label);
+                algorithm = MazeAlgorithm::from_label(algorithm_label, seed, &options);
                 algorithm.initialize(&mut maze);
+                postprocessor = PostProcessor::new(seed, &postprocess_options);
+                solve = None;
+                pending_solve = None;
+                endpoints_placed = false;
+                boundary_openings.clear();
                 path.clear();
                 update_path |= true;
             }
 
-            if let Some((start, goal)) = renderer::MAZE_START_GOAL.lock().unwrap().take() {
-                if let Some((shortest_path, _)) = astar::astar(start, goal, &maze) {
-                    path = shortest_path;
-                } else {
+            if let Some(o) = renderer::MAZE_LONGEST_PATH.lock().unwrap().take() {
+                longest_path = o;
+            }
+
+            let mut rebuild_solver = reset;
+
+            if let Some(label) = renderer::MAZE_SOLVER.lock().unwrap().take() {
+                solver_label = label;
+                rebuild_solver |= true;
+            }
+
+            if let Some(o) = renderer::MAZE_SOLVER_OPTIONS.lock().unwrap().take() {
+                solver_options = o;
+                rebuild_solver |= true;
+            }
+
+            if rebuild_solver {
+                solver = MazeSolver::from_label(solver_label, seed, &solver_options);
+
+                // A new solver cannot pick up where the old one left off, so the current search starts over.
+                if let Some(state) = solve.take() {
+                    pending_solve = Some((state.start, state.goal));
                     path.clear();
+                    update_path |= true;
                 }
+            }
 
+            if let Some(endpoints) = renderer::MAZE_START_GOAL.lock().unwrap().take() {
+                pending_solve = Some(endpoints);
+                solve = None;
+                path.clear();
                 update_path |= true;
             }
 
+            // Solvers look at the maze when they start, such as to find its dead ends, so wait until generation and
+            // post-processing are done with it.
+            if maze.finished && postprocessor.finished() {
+                if let Some((start, goal)) = pending_solve.take() {
+                    let mut state = SolveState::new(start, goal);
+                    solver.initialize(&mut state, &maze);
+                    solve = Some(state);
+                }
+            }
+
+            renderer::SOLVE_PENDING.store(pending_solve.is_some(), Ordering::Relaxed);
+
             if update_path {
                 let mut lock = renderer::PATH_LOCK.lock().unwrap();
                 {
@@ -110,10 +224,24 @@ fn main() {
         maze_size: START_MAZE_SIZE,
         frame_time_us: START_FRAME_TIME_US,
         algorithm: START_ALGORITHM,
+        seed,
+        seed_text: seed.to_string(),
+        reseed: true,
+        options: AlgorithmOptions::default(),
+        postprocess: PostProcessOptions::default(),
+        solver: SolverLabel::default(),
+        solver_options: SolverOptions::default(),
+        solve: None,
+        rule_text: AlgorithmOptions::default().rule.to_string(),
         info_window_open: true,
         wall_width: 0.3,
         selected_start: None,
         selected_goal: None,
+        heatmap: false,
+        heatmap_origin: None,
+        distance_map: None,
+        longest_path: LongestPathOptions::default(),
+        stats: None,
         path: HashSet::new(),
     };
 
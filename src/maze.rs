@@ -16,6 +16,7 @@ pub struct MazeState {
     pub finished: bool,
     visited: HashSet<UVec2>,
     finalized: HashSet<UVec2>,
+    excluded: HashSet<UVec2>,
 }
 
 impl MazeState {
@@ -25,13 +26,26 @@ impl MazeState {
             head: size,
             finished: false,
             visited: HashSet::new(),
-            wall_head: (size.x - 1) * size.y + size.x * (size.y - 1),
+            wall_head: wall_count(size),
             finalized: HashSet::new(),
+            excluded: HashSet::new(),
             neighbors: Neighbors::new(size),
             size,
         }
     }
 
+    /// Creates a maze with the given passages open, for building small mazes by hand in tests.
+    #[cfg(test)]
+    pub(crate) fn from_passages(size: UVec2, passages: &[(UVec2, Direction)]) -> MazeState {
+        let mut maze = MazeState::new(size);
+
+        for &(cell, dir) in passages {
+            maze.neighbors.open(cell, dir);
+        }
+
+        maze
+    }
+
     #[inline]
     pub fn set_visited(&mut self, cell: UVec2) {
         self.visited.insert(cell);
@@ -52,6 +66,12 @@ impl MazeState {
         self.finalized.remove(&cell);
     }
 
+    /// Marks a cell as solid rock that is no longer part of the maze.
+    #[inline]
+    pub fn set_excluded(&mut self, cell: UVec2) {
+        self.excluded.insert(cell);
+    }
+
     #[inline]
     pub fn visited(&self, cell: UVec2) -> bool {
         self.visited.contains(&cell)
@@ -62,6 +82,11 @@ impl MazeState {
         self.finalized.contains(&cell)
     }
 
+    #[inline]
+    pub fn excluded(&self, cell: UVec2) -> bool {
+        self.excluded.contains(&cell)
+    }
+
     #[inline]
     pub fn finish(&mut self) {
         self.finished = true;
@@ -69,33 +94,67 @@ impl MazeState {
         self.wall_head = self.walls();
     }
 
+    /// Whether the cell lies inside the maze.
+    #[inline]
+    pub fn contains(&self, cell: UVec2) -> bool {
+        cell.cmplt(self.size).all()
+    }
+
+    /// The open sides of a cell that lead to another cell, leaving out any openings in the outer wall.
+    #[inline]
+    pub fn passages(&self, cell: UVec2) -> Directions {
+        self.neighbors[cell] & !self.edges(cell)
+    }
+
     /// Computes the [`Directions`] where the given cell touches the edge of the maze.
     #[inline]
     pub fn edges(&self, cell: UVec2) -> Directions {
         let mut d = Directions::NONE;
 
+        // A maze one cell wide or tall has both edges on the same cell.
         if cell.x == 0 {
             d |= Directions::WEST;
-        } else if cell.x == self.size.x - 1 {
+        }
+
+        if cell.x == self.size.x - 1 {
             d |= Directions::EAST;
         }
 
         if cell.y == 0 {
             d |= Directions::SOUTH;
-        } else if cell.y == self.size.y - 1 {
+        }
+
+        if cell.y == self.size.y - 1 {
             d |= Directions::NORTH;
         }
 
         d
     }
 
+    /// The index of the wall on the given side of a cell, as used by [`MazeState::wall_head`].
+    #[inline]
+    pub fn wall_index(&self, cell: UVec2, dir: Direction) -> u32 {
+        match dir {
+            Direction::East => cell.y * (self.size.x - 1) + cell.x,
+            Direction::West => cell.y * (self.size.x - 1) + cell.x - 1,
+            Direction::North => cell.y * self.size.x + cell.x + (self.size.x - 1) * self.size.y,
+            Direction::South => (cell.y - 1) * self.size.x + cell.x + (self.size.x - 1) * self.size.y,
+        }
+    }
+
     #[inline]
     pub fn walls(&self) -> u32 {
-        (self.size.x - 1) * self.size.y + self.size.x * (self.size.y - 1)
+        wall_count(self.size)
     }
 }
 
-#[derive(Debug, Clone)]
+/// Number of inner walls in a maze of `size`, such as `n - 1` for a single row or column of `n` cells. A dimension of
+/// zero adds no walls instead of underflowing.
+fn wall_count(size: UVec2) -> u32 {
+    size.x.saturating_sub(1) * size.y + size.x * size.y.saturating_sub(1)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Neighbors {
     v: Vec<Directions>,
     width: u32,
@@ -0,0 +1,36 @@
+use glam::{UVec2, Vec2};
+use rand::{rngs::SmallRng, Rng};
+
+/// Bilinearly interpolated random values on a square lattice.
+pub(crate) struct ValueNoise {
+    values: Vec<f32>,
+    width: usize,
+    scale: f32,
+}
+
+impl ValueNoise {
+    pub(crate) fn new(rng: &mut SmallRng, size: UVec2, scale: f32) -> ValueNoise {
+        let scale = scale.max(1.0);
+        let width = (size.x as f32 / scale) as usize + 2;
+        let height = (size.y as f32 / scale) as usize + 2;
+
+        ValueNoise {
+            values: (0..width * height).map(|_| rng.random()).collect(),
+            width,
+            scale,
+        }
+    }
+
+    pub(crate) fn sample(&self, p: Vec2) -> f32 {
+        let p = p / self.scale;
+        let (x, y) = (p.x as usize, p.y as usize);
+        let t = p.fract();
+        let t = t * t * (3.0 - 2.0 * t);
+
+        let value = |x: usize, y: usize| self.values[y * self.width + x];
+        let bottom = value(x, y) + (value(x + 1, y) - value(x, y)) * t.x;
+        let top = value(x, y + 1) + (value(x + 1, y + 1) - value(x, y + 1)) * t.x;
+
+        bottom + (top - bottom) * t.y
+    }
+}
@@ -0,0 +1,81 @@
+use glam::UVec2;
+use rand::{rngs::SmallRng, seq::SliceRandom, Rng, SeedableRng};
+
+use crate::{direction::Directions, maze::MazeState};
+
+use super::PostProcess;
+
+/// Removes dead ends by opening one of their walls, turning a perfect maze into one with loops.
+#[derive(Debug, Clone)]
+pub struct Braid {
+    seed: u64,
+    rng: SmallRng,
+    probability: f32,
+    dead_ends: Vec<UVec2>,
+}
+
+impl Braid {
+    /// Creates the pass, which removes each dead end with the given `probability`.
+    pub fn new(seed: u64, probability: f32) -> Braid {
+        Braid {
+            seed,
+            rng: SmallRng::seed_from_u64(seed),
+            probability,
+            dead_ends: Vec::new(),
+        }
+    }
+}
+
+fn is_dead_end(maze: &MazeState, cell: UVec2) -> bool {
+    maze.passages(cell).into_iter().count() == 1
+}
+
+impl PostProcess for Braid {
+    fn initialize(&mut self, maze: &mut MazeState) {
+        self.rng = SmallRng::seed_from_u64(self.seed);
+        self.dead_ends.clear();
+
+        for y in 0..maze.size.y {
+            for x in 0..maze.size.x {
+                let cell = UVec2::new(x, y);
+
+                if is_dead_end(maze, cell) {
+                    self.dead_ends.push(cell);
+                }
+            }
+        }
+
+        self.dead_ends.shuffle(&mut self.rng);
+    }
+
+    fn step(&mut self, maze: &mut MazeState) -> bool {
+        let Some(cell) = self.dead_ends.pop() else {
+            return false;
+        };
+
+        maze.head = cell;
+
+        // Opening a neighboring dead end may already have removed this one.
+        if !is_dead_end(maze, cell) || !self.rng.random_bool(self.probability.clamp(0.0, 1.0) as f64) {
+            return true;
+        }
+
+        let closed = Directions::from_fn(|d| {
+            !maze.neighbors[cell].contains(d) && !maze.edges(cell).contains(d) && !maze.excluded(d.offset(cell))
+        });
+        let dead_ends = Directions::from_fn(|d| closed.contains(d) && is_dead_end(maze, d.offset(cell)));
+
+        let dir = if dead_ends.is_empty() {
+            closed.choose(&mut self.rng)
+        } else {
+            dead_ends.choose(&mut self.rng)
+        };
+
+        if let Some(dir) = dir {
+            maze.neighbors.open(cell, dir);
+            maze.wall_head = maze.wall_index(cell, dir);
+        }
+
+        true
+    }
+}
@@ -0,0 +1,114 @@
+use std::collections::VecDeque;
+
+use crate::maze::MazeState;
+
+mod braid;
+mod sparsify;
+
+pub use braid::Braid;
+pub use sparsify::Sparsify;
+
+/// A pass that modifies a maze after it has been generated.
+pub trait PostProcess {
+    fn initialize(&mut self, maze: &mut MazeState);
+
+    /// Advances the pass by one step, returning `false` once there is nothing left to do.
+    fn step(&mut self, maze: &mut MazeState) -> bool;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PostProcessOptions {
+    /// Chance of [`Braid`] removing each dead end, between zero and one.
+    pub braid: f32,
+    /// Number of times [`Sparsify`] removes every dead end.
+    pub sparsify: u32,
+}
+
+impl Default for PostProcessOptions {
+    fn default() -> Self {
+        PostProcessOptions {
+            braid: 0.0,
+            sparsify: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum MazePass {
+    Braid(Braid),
+    Sparsify(Sparsify),
+}
+
+impl PostProcess for MazePass {
+    fn initialize(&mut self, maze: &mut MazeState) {
+        match self {
+            MazePass::Braid(p) => p.initialize(maze),
+            MazePass::Sparsify(p) => p.initialize(maze),
+        }
+    }
+
+    fn step(&mut self, maze: &mut MazeState) -> bool {
+        match self {
+            MazePass::Braid(p) => p.step(maze),
+            MazePass::Sparsify(p) => p.step(maze),
+        }
+    }
+}
+
+/// Runs the passes enabled in [`PostProcessOptions`] one after another.
+#[derive(Debug, Clone)]
+pub struct PostProcessor {
+    passes: VecDeque<MazePass>,
+    initialized: bool,
+}
+
+impl PostProcessor {
+    pub fn new(seed: u64, options: &PostProcessOptions) -> PostProcessor {
+        let mut passes = VecDeque::new();
+
+        // Sparsify first, since braiding leaves no dead ends behind to remove.
+        if options.sparsify > 0 {
+            passes.push_back(MazePass::Sparsify(Sparsify::new(options.sparsify)));
+        }
+
+        if options.braid > 0.0 {
+            passes.push_back(MazePass::Braid(Braid::new(seed, options.braid)));
+        }
+
+        PostProcessor {
+            passes,
+            initialized: false,
+        }
+    }
+
+    #[inline]
+    pub fn finished(&self) -> bool {
+        self.passes.is_empty()
+    }
+
+    /// Advances the current pass by one step, returning `false` once every pass is done.
+    pub fn step(&mut self, maze: &mut MazeState) -> bool {
+        let Some(pass) = self.passes.front_mut() else {
+            return false;
+        };
+
+        if !self.initialized {
+            pass.initialize(maze);
+            self.initialized = true;
+        }
+
+        if !pass.step(maze) {
+            self.passes.pop_front();
+            self.initialized = false;
+
+            maze.finish();
+        }
+
+        !self.passes.is_empty()
+    }
+
+    /// Runs every pass to completion.
+    pub fn run(&mut self, maze: &mut MazeState) {
+        while self.step(maze) {}
+    }
+}
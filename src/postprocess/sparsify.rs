@@ -0,0 +1,71 @@
+use glam::UVec2;
+
+use crate::maze::MazeState;
+
+use super::PostProcess;
+
+/// Fills in dead ends with solid rock, shortening every dead-end corridor by one cell per iteration.
+#[derive(Debug, Clone)]
+pub struct Sparsify {
+    iterations: u32,
+    iteration: u32,
+    dead_ends: Vec<UVec2>,
+}
+
+impl Sparsify {
+    pub fn new(iterations: u32) -> Sparsify {
+        Sparsify {
+            iterations,
+            iteration: 0,
+            dead_ends: Vec::new(),
+        }
+    }
+
+    fn find_dead_ends(&mut self, maze: &MazeState) {
+        for y in 0..maze.size.y {
+            for x in 0..maze.size.x {
+                let cell = UVec2::new(x, y);
+
+                if maze.passages(cell).into_iter().count() == 1 {
+                    self.dead_ends.push(cell);
+                }
+            }
+        }
+
+        // Cells are popped from the back, so reverse to remove them in scan order.
+        self.dead_ends.reverse();
+    }
+}
+
+impl PostProcess for Sparsify {
+    fn initialize(&mut self, maze: &mut MazeState) {
+        self.iteration = 0;
+        self.dead_ends.clear();
+        self.find_dead_ends(maze);
+    }
+
+    fn step(&mut self, maze: &mut MazeState) -> bool {
+        let Some(cell) = self.dead_ends.pop() else {
+            self.iteration += 1;
+
+            if self.iteration >= self.iterations {
+                return false;
+            }
+
+            self.find_dead_ends(maze);
+            return !self.dead_ends.is_empty();
+        };
+
+        maze.head = cell;
+
+        // Removing the other end of a two-cell corridor leaves this one without any passage, so keep it.
+        let mut open = maze.passages(cell).into_iter();
+        if let (Some(dir), None) = (open.next(), open.next()) {
+            maze.neighbors.close(cell, dir);
+            maze.wall_head = maze.wall_index(cell, dir);
+            maze.set_excluded(cell);
+        }
+
+        true
+    }
+}
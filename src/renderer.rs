@@ -12,23 +12,42 @@ use glam::{UVec2, Vec2};
 use once_cell::sync::Lazy;
 use winit::{event::MouseButton, keyboard::KeyCode};
 
+use maze_algorithms::{
+    algorithms::{
+        AlgorithmLabel, AlgorithmOptions, GrowingTreePick, GrowingTreeStrategy, HuntOrder, KruskalWeights, LifeRule,
+    },
+    analysis::{self, MazeStats, MAX_CORRIDOR_LENGTH},
+    direction::{Corner, Direction},
+    maze::{MazeState, Neighbors},
+    postprocess::PostProcessOptions,
+    solve::{DijkstraWeights, DistanceMap, SolveState, SolverLabel, SolverOptions},
+};
+
 use crate::{
-    algorithms::AlgorithmLabel,
-    direction::Direction,
     input::InputManager,
-    maze::MazeState,
     render::{RenderContext, Renderer},
 };
 
 pub static PAUSED: Lazy<AtomicBool> = Lazy::new(|| true.into());
+pub static FIND_LONGEST_PATH: Lazy<AtomicBool> = Lazy::new(|| false.into());
+pub static SOLVE_PENDING: Lazy<AtomicBool> = Lazy::new(|| false.into());
+pub static MAZE_DONE: Lazy<AtomicBool> = Lazy::new(|| false.into());
 pub static UPDATE_LOCK: Lazy<Mutex<bool>> = Lazy::new(|| Mutex::new(false));
 pub static PATH_LOCK: Lazy<Mutex<bool>> = Lazy::new(|| Mutex::new(false));
 pub static FRAME_TIME: Lazy<Mutex<Option<u64>>> = Lazy::new(|| Mutex::new(None));
 pub static MAZE_SIZE: Lazy<Mutex<Option<UVec2>>> = Lazy::new(|| Mutex::new(None));
 pub static MAZE_START_GOAL: Lazy<Mutex<Option<(UVec2, UVec2)>>> = Lazy::new(|| Mutex::new(None));
 pub static MAZE_ALGORITHM: Lazy<Mutex<Option<AlgorithmLabel>>> = Lazy::new(|| Mutex::new(None));
+pub static MAZE_SEED: Lazy<Mutex<Option<u64>>> = Lazy::new(|| Mutex::new(None));
+pub static MAZE_OPTIONS: Lazy<Mutex<Option<AlgorithmOptions>>> = Lazy::new(|| Mutex::new(None));
+pub static MAZE_POSTPROCESS: Lazy<Mutex<Option<PostProcessOptions>>> = Lazy::new(|| Mutex::new(None));
+pub static MAZE_SOLVER: Lazy<Mutex<Option<SolverLabel>>> = Lazy::new(|| Mutex::new(None));
+pub static MAZE_SOLVER_OPTIONS: Lazy<Mutex<Option<SolverOptions>>> = Lazy::new(|| Mutex::new(None));
+pub static MAZE_LONGEST_PATH: Lazy<Mutex<Option<LongestPathOptions>>> = Lazy::new(|| Mutex::new(None));
+pub static MAZE_ENDPOINTS: Lazy<Mutex<Option<(UVec2, UVec2)>>> = Lazy::new(|| Mutex::new(None));
 pub static MAZE_STATE: Lazy<Mutex<MazeState>> = Lazy::new(|| Mutex::new(MazeState::new(UVec2::ONE)));
 pub static MAZE_PATH: Lazy<Mutex<HashSet<UVec2>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+pub static SOLVE_STATE: Lazy<Mutex<Option<SolveState>>> = Lazy::new(|| Mutex::new(None));
 
 const WALL_COLOR: [f32; 4] = [0.122, 0.137, 0.208, 1.0];
 const CELL_COLOR: [u8; 4] = [59, 66, 97, 255];
@@ -40,6 +59,33 @@ const START_COLOR: [u8; 4] = [187, 154, 247, 255];
 const GOAL_BAD_COLOR: [u8; 4] = [197, 59, 83, 255];
 const START_BAD_COLOR: [u8; 4] = [255, 117, 127, 255];
 const PATH_COLOR: [u8; 4] = [255, 199, 119, 255];
+const OPEN_COLOR: [u8; 4] = [224, 175, 104, 255];
+const CLOSED_COLOR: [u8; 4] = [122, 162, 247, 255];
+const BACKWARD_OPEN_COLOR: [u8; 4] = [158, 206, 106, 255];
+const BACKWARD_CLOSED_COLOR: [u8; 4] = [247, 118, 142, 255];
+const MEETING_COLOR: [u8; 4] = [192, 202, 245, 255];
+const HEATMAP_COLORS: [[f32; 3]; 3] = [[61.0, 89.0, 161.0], [115.0, 218.0, 202.0], [224.0, 175.0, 104.0]];
+
+/// Statistics of the maze, along with the passages and endpoints they were computed for.
+pub type CachedStats = (Neighbors, Option<(UVec2, UVec2)>, MazeStats);
+
+/// How the worker places the start and goal at the two ends of the longest path through the maze.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LongestPathOptions {
+    /// Place them as soon as the maze is done, instead of only when asked to.
+    pub automatic: bool,
+    /// Place them on the outer wall and open it next to each, making an entrance and an exit.
+    pub boundary: bool,
+}
+
+impl Default for LongestPathOptions {
+    fn default() -> Self {
+        LongestPathOptions {
+            automatic: true,
+            boundary: false,
+        }
+    }
+}
 
 pub struct MazeRenderer {
     pub pos: Vec2,
@@ -50,9 +96,72 @@ pub struct MazeRenderer {
     pub frame_time_us: u64,
     pub path: HashSet<UVec2>,
     pub algorithm: AlgorithmLabel,
+    pub seed: u64,
+    /// The seed as typed, which is kept separately so that every `u64` can be entered exactly.
+    pub seed_text: String,
+    pub reseed: bool,
+    pub options: AlgorithmOptions,
+    pub postprocess: PostProcessOptions,
+    pub solver: SolverLabel,
+    pub solver_options: SolverOptions,
+    pub solve: Option<SolveState>,
+    pub rule_text: String,
     pub info_window_open: bool,
     pub selected_start: Option<UVec2>,
     pub selected_goal: Option<UVec2>,
+    pub heatmap: bool,
+    pub heatmap_origin: Option<UVec2>,
+    /// The distances from [`MazeRenderer::heatmap_origin`], along with the passages they were computed for.
+    pub distance_map: Option<(Neighbors, DistanceMap)>,
+    pub longest_path: LongestPathOptions,
+    pub stats: Option<CachedStats>,
+}
+
+impl MazeRenderer {
+    /// The color of the passage between two cells while they are being searched, or of a single cell if both are the
+    /// same.
+    fn search_color(&self, a: UVec2, b: UVec2) -> Option<[u8; 4]> {
+        let solve = self.solve.as_ref()?;
+
+        let reached = |c| solve.open(c) || solve.closed(c);
+        let reached_backward = |c| solve.open_backward(c) || solve.closed_backward(c);
+
+        if reached(a) && reached(b) {
+            if solve.open(a) || solve.open(b) {
+                Some(OPEN_COLOR)
+            } else {
+                Some(CLOSED_COLOR)
+            }
+        } else if reached_backward(a) && reached_backward(b) {
+            if solve.open_backward(a) || solve.open_backward(b) {
+                Some(BACKWARD_OPEN_COLOR)
+            } else {
+                Some(BACKWARD_CLOSED_COLOR)
+            }
+        } else {
+            None
+        }
+    }
+
+    /// The color of the passage between two cells on the distance heatmap, or of a single cell if both are the same.
+    fn heatmap_color(&self, a: UVec2, b: UVec2) -> Option<[u8; 4]> {
+        let (_, map) = self.distance_map.as_ref()?;
+        let t = (map.get(a)? + map.get(b)?) as f32 / (2 * map.max.max(1)) as f32;
+
+        let (low, high, t) = if t < 0.5 {
+            (HEATMAP_COLORS[0], HEATMAP_COLORS[1], t * 2.0)
+        } else {
+            (HEATMAP_COLORS[1], HEATMAP_COLORS[2], t * 2.0 - 1.0)
+        };
+
+        let channel = |i: usize| (low[i] + (high[i] - low[i]) * t) as u8;
+        Some([channel(0), channel(1), channel(2), 255])
+    }
+
+    /// Whether the solver finished without finding a path between the selected start and goal.
+    fn unsolvable(&self) -> bool {
+        self.solve.as_ref().is_some_and(|s| s.finished && s.solution.is_none())
+    }
 }
 
 impl Renderer for MazeRenderer {
@@ -74,6 +183,12 @@ impl Renderer for MazeRenderer {
         }
 
         if input.key_pressed(KeyCode::KeyR) {
+            if self.reseed {
+                self.seed = rand::random();
+                self.seed_text = self.seed.to_string();
+                MAZE_SEED.lock().unwrap().replace(self.seed);
+            }
+
             MAZE_SIZE.lock().unwrap().replace(self.maze_size);
         }
 
@@ -96,13 +211,15 @@ impl Renderer for MazeRenderer {
 
             if input.mouse_pressed(MouseButton::Left) {
                 let cell = ((target * self.scale + 0.5 + self.pos) * self.maze.size.as_vec2()).as_uvec2();
-                self.selected_start = Some(cell);
 
-                if self.selected_goal.is_some() {
-                    MAZE_START_GOAL
-                        .lock()
-                        .unwrap()
-                        .replace((self.selected_start.unwrap(), self.selected_goal.unwrap()));
+                if self.heatmap {
+                    self.heatmap_origin = Some(cell);
+                } else {
+                    self.selected_start = Some(cell);
+
+                    if let Some(goal) = self.selected_goal {
+                        MAZE_START_GOAL.lock().unwrap().replace((cell, goal));
+                    }
                 }
             }
 
@@ -110,11 +227,8 @@ impl Renderer for MazeRenderer {
                 let cell = ((target * self.scale + 0.5 + self.pos) * self.maze.size.as_vec2()).as_uvec2();
                 self.selected_goal = Some(cell);
 
-                if self.selected_start.is_some() {
-                    MAZE_START_GOAL
-                        .lock()
-                        .unwrap()
-                        .replace((self.selected_start.unwrap(), self.selected_goal.unwrap()));
+                if let Some(start) = self.selected_start {
+                    MAZE_START_GOAL.lock().unwrap().replace((start, cell));
                 }
             }
 
@@ -137,6 +251,7 @@ impl Renderer for MazeRenderer {
             let mut lock = UPDATE_LOCK.lock().unwrap();
             if *lock {
                 mem::swap(&mut self.maze, &mut MAZE_STATE.lock().unwrap());
+                mem::swap(&mut self.solve, &mut SOLVE_STATE.lock().unwrap());
             }
             *lock = false;
         }
@@ -148,6 +263,26 @@ impl Renderer for MazeRenderer {
             *lock = false;
         }
 
+        if let Some((start, goal)) = MAZE_ENDPOINTS.lock().unwrap().take() {
+            self.selected_start = Some(start);
+            self.selected_goal = Some(goal);
+        }
+
+        // Recompute the distances whenever the passages change, so the heatmap follows the maze as it is generated.
+        match self.heatmap_origin.filter(|_| self.heatmap) {
+            Some(origin) => {
+                if self
+                    .distance_map
+                    .as_ref()
+                    .is_none_or(|(neighbors, map)| *neighbors != self.maze.neighbors || map.origin != origin)
+                {
+                    let map = DistanceMap::new(&self.maze, origin);
+                    self.distance_map = Some((self.maze.neighbors.clone(), map));
+                }
+            },
+            None => self.distance_map = None,
+        }
+
         ctx.set_view_pos(self.pos);
         ctx.set_view_scale(self.scale);
         ctx.set_clear_color(WALL_COLOR);
@@ -179,8 +314,27 @@ impl Renderer for MazeRenderer {
                     open_walls_y.insert(cell);
                 }
 
+                // Openings in the outer wall to the west and south have no cell on the other side to draw them.
+                if x == 0 && neighbors.contains(Direction::West) {
+                    let min = Vec2::new(wall_offset.x - full_cell_size.x, min.y);
+                    ctx.draw_rect(min - 0.5, min + Vec2::new(wall_size.x, cell_size.y) - 0.5, CELL_COLOR);
+                }
+
+                if y == 0 && neighbors.contains(Direction::South) {
+                    let min = Vec2::new(min.x, wall_offset.y - full_cell_size.y);
+                    ctx.draw_rect(min - 0.5, min + Vec2::new(cell_size.x, wall_size.y) - 0.5, CELL_COLOR);
+                }
+
+                if self.maze.excluded(cell) && cell != self.maze.head {
+                    continue;
+                }
+
                 let mut color = if self.path.contains(&cell) {
                     PATH_COLOR
+                } else if let Some(color) = self.search_color(cell, cell) {
+                    color
+                } else if let Some(color) = self.heatmap_color(cell, cell) {
+                    color
                 } else if self.maze.finalized(cell) {
                     FINALIZED_COLOR
                 } else if self.maze.visited(cell) {
@@ -191,7 +345,7 @@ impl Renderer for MazeRenderer {
 
                 if let Some(start) = self.selected_start {
                     if cell == start {
-                        if self.path.is_empty() && self.selected_goal.is_some() {
+                        if self.unsolvable() {
                             color = START_BAD_COLOR;
                         } else {
                             color = START_COLOR;
@@ -201,7 +355,7 @@ impl Renderer for MazeRenderer {
 
                 if let Some(goal) = self.selected_goal {
                     if cell == goal {
-                        if self.path.is_empty() && self.selected_start.is_some() {
+                        if self.unsolvable() {
                             color = GOAL_BAD_COLOR;
                         } else {
                             color = GOAL_COLOR;
@@ -209,7 +363,11 @@ impl Renderer for MazeRenderer {
                     }
                 }
 
-                if cell == self.maze.head {
+                if self.solve.as_ref().is_some_and(|s| s.meeting == Some(cell)) {
+                    color = MEETING_COLOR;
+                }
+
+                if cell == self.maze.head || self.solve.as_ref().is_some_and(|s| s.head == Some(cell)) {
                     color = HEAD_COLOR;
                 }
 
@@ -226,6 +384,10 @@ impl Renderer for MazeRenderer {
 
             let color = if self.path.contains(&west) && self.path.contains(&east) {
                 PATH_COLOR
+            } else if let Some(color) = self.search_color(west, east) {
+                color
+            } else if let Some(color) = self.heatmap_color(west, east) {
+                color
             } else if self.maze.finalized(west) && self.maze.finalized(east) {
                 FINALIZED_COLOR
             } else if self.maze.visited(west) && self.maze.visited(east) {
@@ -246,6 +408,10 @@ impl Renderer for MazeRenderer {
 
             let color = if self.path.contains(&south) && self.path.contains(&north) {
                 PATH_COLOR
+            } else if let Some(color) = self.search_color(south, north) {
+                color
+            } else if let Some(color) = self.heatmap_color(south, north) {
+                color
             } else if self.maze.finalized(south) && self.maze.finalized(north) {
                 FINALIZED_COLOR
             } else if self.maze.visited(south) && self.maze.visited(north) {
@@ -280,6 +446,12 @@ impl Renderer for MazeRenderer {
 
     fn gui(&mut self, ctx: &Context) {
         let before = self.algorithm;
+        let seed_before = self.seed;
+        let options_before = self.options.clone();
+        let postprocess_before = self.postprocess.clone();
+        let solver_before = self.solver;
+        let solver_options_before = self.solver_options.clone();
+        let longest_path_before = self.longest_path;
         egui::Window::new("").open(&mut self.info_window_open).show(ctx, |ui| {
             egui::ComboBox::from_label("Algorithm")
                 .selected_text(format!("{:?}", self.algorithm))
@@ -299,11 +471,365 @@ impl Renderer for MazeRenderer {
                         AlgorithmLabel::RecursiveDivision,
                         "Recursive Division",
                     );
+                    ui.selectable_value(&mut self.algorithm, AlgorithmLabel::Wilson, "Wilson");
+                    ui.selectable_value(&mut self.algorithm, AlgorithmLabel::AldousBroder, "Aldous-Broder");
+                    ui.selectable_value(&mut self.algorithm, AlgorithmLabel::HuntAndKill, "Hunt and Kill");
+                    ui.selectable_value(&mut self.algorithm, AlgorithmLabel::BinaryTree, "Binary Tree");
+                    ui.selectable_value(&mut self.algorithm, AlgorithmLabel::BlobbyDivision, "Blobby Division");
+                    ui.selectable_value(&mut self.algorithm, AlgorithmLabel::OriginShift, "Origin Shift");
+                    ui.selectable_value(
+                        &mut self.algorithm,
+                        AlgorithmLabel::CellularAutomaton,
+                        "Cellular Automaton",
+                    );
+                });
+
+            ui.horizontal(|ui| {
+                ui.label("Seed");
+
+                if ui.text_edit_singleline(&mut self.seed_text).changed() {
+                    if let Ok(seed) = self.seed_text.trim().parse() {
+                        self.seed = seed;
+                    }
+                }
+
+                if ui.button("Randomize").clicked() {
+                    self.seed = rand::random();
+                    self.seed_text = self.seed.to_string();
+                }
+            });
+
+            if let Err(e) = self.seed_text.trim().parse::<u64>() {
+                ui.colored_label(egui::Color32::from_rgb(255, 117, 127), format!("invalid seed: {e}"));
+            }
+
+            ui.checkbox(&mut self.reseed, "New seed on reset");
+
+            match self.algorithm {
+                AlgorithmLabel::GrowingTree => {
+                    let strategy = &mut self.options.strategy;
+                    let mixed = matches!(strategy, GrowingTreeStrategy::Mixed { .. });
+                    let picks = [
+                        (GrowingTreePick::Newest, "Newest"),
+                        (GrowingTreePick::Oldest, "Oldest"),
+                        (GrowingTreePick::Random, "Random"),
+                        (GrowingTreePick::Middle, "Middle"),
+                    ];
+
+                    ui.horizontal(|ui| {
+                        ui.label("Strategy");
+
+                        for (pick, name) in picks {
+                            ui.radio_value(strategy, GrowingTreeStrategy::Single(pick), name);
+                        }
+
+                        if ui.radio(mixed, "Mixed").clicked() && !mixed {
+                            *strategy = GrowingTreeStrategy::default();
+                        }
+                    });
+
+                    if let GrowingTreeStrategy::Mixed { first, second, weight } = strategy {
+                        for (label, selected) in [("First", first), ("Otherwise", second)] {
+                            ui.horizontal(|ui| {
+                                ui.label(label);
+
+                                for (pick, name) in picks {
+                                    ui.radio_value(selected, pick, name);
+                                }
+                            });
+                        }
+
+                        ui.add(egui::Slider::new(weight, 0.0..=1.0).text("Chance of first"));
+                    }
+                },
+                AlgorithmLabel::Kruskal => {
+                    let weights = &mut self.options.weights;
+                    let directional = matches!(weights, KruskalWeights::Directional { .. });
+                    let noise = matches!(weights, KruskalWeights::Noise { .. });
+
+                    ui.horizontal(|ui| {
+                        ui.label("Weights");
+                        ui.radio_value(weights, KruskalWeights::Uniform, "Uniform");
+
+                        if ui.radio(directional, "Directional").clicked() && !directional {
+                            *weights = KruskalWeights::Directional { horizontal: 0.75 };
+                        }
+
+                        if ui.radio(noise, "Noise").clicked() && !noise {
+                            *weights = KruskalWeights::Noise { scale: 8.0 };
+                        }
+                    });
+
+                    match weights {
+                        KruskalWeights::Directional { horizontal } => {
+                            ui.add(egui::Slider::new(horizontal, 0.0..=1.0).text("Horizontal"));
+                        },
+                        KruskalWeights::Noise { scale } => {
+                            ui.add(egui::Slider::new(scale, 1.0..=64.0).logarithmic(true).text("Scale"));
+                        },
+                        _ => {},
+                    }
+                },
+                AlgorithmLabel::RecursiveDivision => {
+                    ui.horizontal(|ui| {
+                        ui.label("Room size");
+                        ui.add(egui::DragValue::new(&mut self.options.room_size.x).range(0..=64));
+                        ui.label("x");
+                        ui.add(egui::DragValue::new(&mut self.options.room_size.y).range(0..=64));
+                    });
+
+                    let mut biased = self.options.split_bias.is_some();
+                    ui.checkbox(&mut biased, "Split bias");
+
+                    if biased {
+                        let bias = self.options.split_bias.get_or_insert(0.5);
+                        ui.add(egui::Slider::new(bias, 0.0..=1.0).text("Horizontal"));
+                    } else {
+                        self.options.split_bias = None;
+                    }
+
+                    ui.add(egui::Slider::new(&mut self.options.gaps, 1..=8).text("Gaps per wall"));
+                },
+                AlgorithmLabel::OriginShift => {
+                    let mut limited = self.options.shift_budget.is_some();
+                    ui.checkbox(&mut limited, "Step budget");
+
+                    if limited {
+                        let budget = self.options.shift_budget.get_or_insert(1000);
+                        ui.add(egui::DragValue::new(budget).speed(10));
+                    } else {
+                        self.options.shift_budget = None;
+                    }
+                },
+                AlgorithmLabel::CellularAutomaton => {
+                    ui.horizontal(|ui| {
+                        ui.label("Rule");
+
+                        if ui.text_edit_singleline(&mut self.rule_text).changed() {
+                            if let Ok(rule) = self.rule_text.parse() {
+                                self.options.rule = rule;
+                            }
+                        }
+
+                        for (name, rule) in [("Maze", LifeRule::MAZE), ("Mazectric", LifeRule::MAZECTRIC)] {
+                            if ui.button(name).clicked() {
+                                self.options.rule = rule;
+                                self.rule_text = rule.to_string();
+                            }
+                        }
+                    });
+
+                    if let Err(e) = self.rule_text.parse::<LifeRule>() {
+                        ui.colored_label(egui::Color32::from_rgb(255, 117, 127), e);
+                    }
+                },
+                AlgorithmLabel::AldousBroder => {
+                    let mut hybrid = self.options.hybrid_threshold.is_some();
+                    ui.checkbox(&mut hybrid, "Switch to Wilson");
+
+                    if hybrid {
+                        let threshold = self.options.hybrid_threshold.get_or_insert(0.5);
+                        ui.add(egui::Slider::new(threshold, 0.0..=1.0).text("Visited fraction"));
+                    } else {
+                        self.options.hybrid_threshold = None;
+                    }
+                },
+                AlgorithmLabel::HuntAndKill => {
+                    ui.horizontal(|ui| {
+                        ui.label("Hunt order");
+                        ui.radio_value(&mut self.options.hunt_order, HuntOrder::RowMajor, "Row major");
+                        ui.radio_value(&mut self.options.hunt_order, HuntOrder::Random, "Random");
+                    });
+                },
+                AlgorithmLabel::BinaryTree => {
+                    egui::ComboBox::from_label("Bias")
+                        .selected_text(format!("{:?}", self.options.bias))
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(&mut self.options.bias, Corner::NorthEast, "North East");
+                            ui.selectable_value(&mut self.options.bias, Corner::NorthWest, "North West");
+                            ui.selectable_value(&mut self.options.bias, Corner::SouthWest, "South West");
+                            ui.selectable_value(&mut self.options.bias, Corner::SouthEast, "South East");
+                        });
+                },
+                _ => {},
+            }
+
+            ui.separator();
+            ui.add(
+                egui::Slider::new(&mut self.postprocess.braid, 0.0..=1.0)
+                    .text("Braid")
+                    .custom_formatter(|v, _| format!("{:.0}%", v * 100.0)),
+            );
+            ui.add(egui::Slider::new(&mut self.postprocess.sparsify, 0..=64).text("Sparsify iterations"));
+
+            ui.separator();
+            ui.checkbox(&mut self.heatmap, "Distance heatmap from left click");
+
+            if let Some((_, map)) = &self.distance_map {
+                ui.label(format!("Farthest cell {} steps away", map.max));
+            }
+
+            ui.horizontal(|ui| {
+                if ui.button("Longest path").clicked() {
+                    FIND_LONGEST_PATH.store(true, Ordering::Relaxed);
+                }
+
+                ui.checkbox(&mut self.longest_path.automatic, "When done");
+                ui.checkbox(&mut self.longest_path.boundary, "On boundary");
+            });
+
+            egui::CollapsingHeader::new("Statistics").show(ui, |ui| {
+                // The maze changes every step until it is done, so only analyze the final one.
+                if !MAZE_DONE.load(Ordering::Relaxed) {
+                    ui.label("Waiting for generation to finish");
+                    return;
+                }
+
+                let endpoints = self.selected_start.zip(self.selected_goal);
+
+                if self
+                    .stats
+                    .as_ref()
+                    .is_none_or(|(neighbors, e, _)| *neighbors != self.maze.neighbors || *e != endpoints)
+                {
+                    let stats = analysis::analyze(&self.maze, endpoints);
+                    self.stats = Some((self.maze.neighbors.clone(), endpoints, stats));
+                }
+
+                let (_, _, stats) = self.stats.as_ref().unwrap();
+                let percent = |n: u32| 100.0 * n as f32 / stats.cells.max(1) as f32;
+
+                ui.label(format!("Cells {}", stats.cells));
+                ui.label(format!(
+                    "Dead ends {} ({:.1}%)",
+                    stats.dead_ends,
+                    percent(stats.dead_ends)
+                ));
+                ui.label(format!(
+                    "Junctions {}, crossroads {}",
+                    stats.junctions, stats.crossroads
+                ));
+                ui.label(format!("River factor {:.2} (cells per dead-end branch)", stats.river));
+
+                match (stats.solution_length, stats.solution_coverage) {
+                    (Some(length), Some(coverage)) => {
+                        ui.label(format!("Solution length {length} ({:.1}% of cells)", 100.0 * coverage));
+                    },
+                    _ => {
+                        ui.label("No solution");
+                    },
+                }
+
+                ui.label("Corridor lengths");
+                let most = stats.corridor_lengths.iter().copied().max().unwrap_or(0).max(1);
+
+                for (length, &count) in stats.corridor_lengths.iter().enumerate().skip(1) {
+                    if count == 0 {
+                        continue;
+                    }
+
+                    let plus = if length == MAX_CORRIDOR_LENGTH { "+" } else { "" };
+                    ui.add(
+                        egui::ProgressBar::new(count as f32 / most as f32)
+                            .desired_width(200.0)
+                            .text(format!("{length}{plus}: {count}")),
+                    );
+                }
+            });
+
+            egui::ComboBox::from_label("Solver")
+                .selected_text(format!("{:?}", self.solver))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(
+                        &mut self.solver,
+                        SolverLabel::BreadthFirstSearch,
+                        "Breadth First Search",
+                    );
+                    ui.selectable_value(&mut self.solver, SolverLabel::Dijkstra, "Dijkstra");
+                    ui.selectable_value(&mut self.solver, SolverLabel::AStar, "A*");
+                    ui.selectable_value(&mut self.solver, SolverLabel::LeftWallFollower, "Left Wall Follower");
+                    ui.selectable_value(&mut self.solver, SolverLabel::RightWallFollower, "Right Wall Follower");
+                    ui.selectable_value(&mut self.solver, SolverLabel::Tremaux, "Trémaux");
+                    ui.selectable_value(&mut self.solver, SolverLabel::DeadEndFilling, "Dead End Filling");
+                    ui.selectable_value(
+                        &mut self.solver,
+                        SolverLabel::BidirectionalBreadthFirstSearch,
+                        "Bidirectional Breadth First Search",
+                    );
+                    ui.selectable_value(&mut self.solver, SolverLabel::BidirectionalAStar, "Bidirectional A*");
                 });
 
+            if self.solver == SolverLabel::Dijkstra {
+                let mut noise = matches!(self.solver_options.weights, DijkstraWeights::Noise { .. });
+                ui.checkbox(&mut noise, "Noise weights");
+
+                if noise {
+                    let (mut scale, mut max) = match self.solver_options.weights {
+                        DijkstraWeights::Noise { scale, max } => (scale, max),
+                        _ => (8.0, 10),
+                    };
+
+                    ui.add(egui::Slider::new(&mut scale, 1.0..=64.0).text("Scale"));
+                    ui.add(egui::Slider::new(&mut max, 1..=100).text("Max weight"));
+
+                    self.solver_options.weights = DijkstraWeights::Noise { scale, max };
+                } else {
+                    self.solver_options.weights = DijkstraWeights::Uniform;
+                }
+            }
+
+            if let Some(solve) = &self.solve {
+                if let Some(solution) = &solve.solution {
+                    ui.label(format!(
+                        "Path of {} cells, cost {}, explored {}",
+                        solution.path.len(),
+                        solution.cost,
+                        solution.explored
+                    ));
+                } else if solve.finished {
+                    ui.label(format!("Failed, explored {}", solve.explored()));
+                } else {
+                    ui.label(format!("Explored {}", solve.explored()));
+                }
+            } else if SOLVE_PENDING.load(Ordering::Relaxed) {
+                if self.algorithm == AlgorithmLabel::OriginShift && self.options.shift_budget.is_none() {
+                    ui.label("Waiting for generation to finish, which needs a step budget for origin shift");
+                } else {
+                    ui.label("Waiting for generation to finish");
+                }
+            }
+
             if self.algorithm != before {
                 MAZE_ALGORITHM.lock().unwrap().replace(self.algorithm);
             }
+
+            if self.seed != seed_before {
+                MAZE_SEED.lock().unwrap().replace(self.seed);
+            }
+
+            if self.options != options_before {
+                MAZE_OPTIONS.lock().unwrap().replace(self.options.clone());
+            }
+
+            if self.postprocess != postprocess_before {
+                MAZE_POSTPROCESS.lock().unwrap().replace(self.postprocess.clone());
+            }
+
+            if self.longest_path != longest_path_before {
+                MAZE_LONGEST_PATH.lock().unwrap().replace(self.longest_path);
+            }
+
+            if self.solver_options != solver_options_before {
+                MAZE_SOLVER_OPTIONS.lock().unwrap().replace(self.solver_options.clone());
+            }
+
+            if self.solver != solver_before {
+                MAZE_SOLVER.lock().unwrap().replace(self.solver);
+
+                if let (Some(start), Some(goal)) = (self.selected_start, self.selected_goal) {
+                    MAZE_START_GOAL.lock().unwrap().replace((start, goal));
+                }
+            }
         });
     }
 }
@@ -0,0 +1,32 @@
+use glam::UVec2;
+
+use crate::maze::MazeState;
+
+use super::{dijkstra::BestFirst, SolveState, Solver};
+
+/// Expands cells in order of their distance from the start plus their Manhattan distance to the goal.
+#[derive(Debug, Clone, Default)]
+pub struct AStar {
+    search: BestFirst,
+}
+
+impl AStar {
+    pub fn new() -> AStar {
+        AStar::default()
+    }
+}
+
+fn manhattan(a: UVec2, b: UVec2) -> u32 {
+    a.x.abs_diff(b.x) + a.y.abs_diff(b.y)
+}
+
+impl Solver for AStar {
+    fn initialize(&mut self, state: &mut SolveState, maze: &MazeState) {
+        self.search.initialize(state, maze, manhattan(state.start, state.goal));
+    }
+
+    fn step(&mut self, state: &mut SolveState, maze: &MazeState) {
+        let goal = state.goal;
+        self.search.step(state, maze, |_| 1, |c| manhattan(c, goal));
+    }
+}
@@ -0,0 +1,69 @@
+use std::collections::{hash_map::Entry, VecDeque};
+
+use glam::UVec2;
+use rustc_hash::FxHashMap;
+
+use crate::maze::MazeState;
+
+use super::{reconstruct_path, Solution, SolveState, Solver};
+
+/// Expands cells in order of their distance from the start, which finds a shortest path in an unweighted maze.
+#[derive(Debug, Clone, Default)]
+pub struct BreadthFirstSearch {
+    queue: VecDeque<UVec2>,
+    parents: FxHashMap<UVec2, UVec2>,
+}
+
+impl BreadthFirstSearch {
+    pub fn new() -> BreadthFirstSearch {
+        BreadthFirstSearch::default()
+    }
+}
+
+impl Solver for BreadthFirstSearch {
+    fn initialize(&mut self, state: &mut SolveState, maze: &MazeState) {
+        self.queue.clear();
+        self.parents.clear();
+
+        if !maze.contains(state.start) || !maze.contains(state.goal) {
+            state.finish(None);
+            return;
+        }
+
+        self.queue.push_back(state.start);
+        self.parents.insert(state.start, state.start);
+        state.set_open(state.start);
+    }
+
+    fn step(&mut self, state: &mut SolveState, maze: &MazeState) {
+        let Some(cell) = self.queue.pop_front() else {
+            state.finish(None);
+            return;
+        };
+
+        state.head = Some(cell);
+        state.unset_open(cell);
+        state.set_closed(cell);
+
+        if cell == state.goal {
+            let path = reconstruct_path(&self.parents, state.start, state.goal);
+
+            state.finish(Some(Solution {
+                cost: path.len() as u32 - 1,
+                path,
+                explored: state.explored(),
+            }));
+            return;
+        }
+
+        for dir in maze.passages(cell) {
+            let next = dir.offset(cell);
+
+            if let Entry::Vacant(e) = self.parents.entry(next) {
+                e.insert(cell);
+                self.queue.push_back(next);
+                state.set_open(next);
+            }
+        }
+    }
+}
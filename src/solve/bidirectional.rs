@@ -0,0 +1,203 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use glam::UVec2;
+use rustc_hash::FxHashMap;
+
+use crate::maze::MazeState;
+
+use super::{reconstruct_path, Solution, SolveState, Solver};
+
+/// The Manhattan distance between two cells if `heuristic` is set, and zero otherwise.
+fn estimate(heuristic: bool, cell: UVec2, target: UVec2) -> u32 {
+    if heuristic {
+        cell.x.abs_diff(target.x) + cell.y.abs_diff(target.y)
+    } else {
+        0
+    }
+}
+
+/// One of the two searches run by [`Bidirectional`].
+#[derive(Debug, Clone, Default)]
+struct Search {
+    open: BinaryHeap<(Reverse<u32>, u32, [u32; 2])>,
+    costs: FxHashMap<UVec2, u32>,
+    parents: FxHashMap<UVec2, UVec2>,
+}
+
+impl Search {
+    fn reset(&mut self, from: UVec2, estimate: u32) {
+        self.open.clear();
+        self.costs.clear();
+        self.parents.clear();
+
+        self.open.push((Reverse(estimate), 0, from.to_array()));
+        self.costs.insert(from, 0);
+        self.parents.insert(from, from);
+    }
+
+    /// Drops entries that were superseded by a cheaper route to the same cell.
+    fn prune(&mut self) {
+        while let Some(&(_, cost, cell)) = self.open.peek() {
+            if cost <= self.costs[&UVec2::from_array(cell)] {
+                break;
+            }
+
+            self.open.pop();
+        }
+    }
+
+    /// The lowest estimate of any cell waiting to be expanded.
+    fn min_estimate(&mut self) -> Option<u32> {
+        self.prune();
+        self.open.peek().map(|&(Reverse(estimate), ..)| estimate)
+    }
+}
+
+/// Searches from the start and the goal at the same time, always expanding the side with the smaller frontier, until
+/// the two searches meet. The search from the goal is shown with the backward sets of [`SolveState`].
+#[derive(Debug, Clone)]
+pub struct Bidirectional {
+    heuristic: bool,
+    forward: Search,
+    backward: Search,
+    best: Option<(u32, UVec2)>,
+}
+
+impl Bidirectional {
+    /// Creates the solver. Without a `heuristic` both sides are breadth first searches, and with one they are A*
+    /// searches towards the opposite end.
+    pub fn new(heuristic: bool) -> Bidirectional {
+        Bidirectional {
+            heuristic,
+            forward: Search::default(),
+            backward: Search::default(),
+            best: None,
+        }
+    }
+
+    /// Whether no path shorter than the best one found so far can remain.
+    fn done(&mut self, mu: u32) -> bool {
+        let (Some(f), Some(b)) = (self.forward.min_estimate(), self.backward.min_estimate()) else {
+            return true;
+        };
+
+        // With a heuristic each side's lowest estimate bounds every path through it on its own. Without one, the
+        // lowest costs on both sides add up to a bound instead.
+        if self.heuristic {
+            f.max(b) >= mu
+        } else {
+            f + b >= mu
+        }
+    }
+
+    fn finish(&self, state: &mut SolveState) {
+        let Some((cost, meeting)) = self.best else {
+            state.finish(None);
+            return;
+        };
+
+        let mut path = reconstruct_path(&self.forward.parents, state.start, meeting);
+        let mut back = reconstruct_path(&self.backward.parents, state.goal, meeting);
+        back.pop();
+        path.extend(back.into_iter().rev());
+
+        state.finish(Some(Solution {
+            path,
+            cost,
+            explored: state.explored(),
+        }));
+    }
+}
+
+impl Solver for Bidirectional {
+    fn initialize(&mut self, state: &mut SolveState, maze: &MazeState) {
+        self.best = None;
+
+        if !maze.contains(state.start) || !maze.contains(state.goal) {
+            state.finish(None);
+            return;
+        }
+
+        self.forward
+            .reset(state.start, estimate(self.heuristic, state.start, state.goal));
+        self.backward
+            .reset(state.goal, estimate(self.heuristic, state.goal, state.start));
+        state.set_open(state.start);
+        state.set_open_backward(state.goal);
+
+        if state.start == state.goal {
+            self.best = Some((0, state.start));
+            state.meeting = Some(state.start);
+        }
+    }
+
+    fn step(&mut self, state: &mut SolveState, maze: &MazeState) {
+        if let Some((mu, _)) = self.best {
+            if self.done(mu) {
+                self.finish(state);
+                return;
+            }
+        }
+
+        self.forward.prune();
+        self.backward.prune();
+
+        let is_forward = self.forward.open.len() <= self.backward.open.len();
+        let (search, other, target) = if is_forward {
+            (&mut self.forward, &self.backward, state.goal)
+        } else {
+            (&mut self.backward, &self.forward, state.start)
+        };
+
+        // Either side running out of cells means the two ends are not connected.
+        let Some((_, cost, cell)) = search.open.pop() else {
+            self.finish(state);
+            return;
+        };
+
+        let cell = UVec2::from_array(cell);
+        state.head = Some(cell);
+
+        if is_forward {
+            state.unset_open(cell);
+            state.set_closed(cell);
+        } else {
+            state.unset_open_backward(cell);
+            state.set_closed_backward(cell);
+        }
+
+        let mut best = self.best;
+
+        for dir in maze.passages(cell) {
+            let next = dir.offset(cell);
+            let next_cost = cost + 1;
+
+            if search.costs.get(&next).is_some_and(|&c| c <= next_cost) {
+                continue;
+            }
+
+            search.costs.insert(next, next_cost);
+            search.parents.insert(next, cell);
+
+            let estimate = estimate(self.heuristic, next, target);
+            search
+                .open
+                .push((Reverse(next_cost + estimate), next_cost, next.to_array()));
+
+            if is_forward {
+                state.set_open(next);
+            } else {
+                state.set_open_backward(next);
+            }
+
+            if let Some(&other_cost) = other.costs.get(&next) {
+                if best.is_none_or(|(mu, _)| next_cost + other_cost < mu) {
+                    best = Some((next_cost + other_cost, next));
+                    state.meeting = Some(next);
+                }
+            }
+        }
+
+        self.best = best;
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::{hash_map::Entry, VecDeque};
+
+use glam::UVec2;
+use rustc_hash::FxHashMap;
+
+use crate::maze::MazeState;
+
+use super::{reconstruct_path, Solution, SolveState, Solver};
+
+/// Fills in every dead end other than the start and goal until none are left, so that only the cells on a path
+/// between them remain. Filled cells are shown as closed.
+#[derive(Debug, Clone, Default)]
+pub struct DeadEndFilling {
+    dead_ends: Vec<UVec2>,
+}
+
+impl DeadEndFilling {
+    pub fn new() -> DeadEndFilling {
+        DeadEndFilling::default()
+    }
+
+    fn is_dead_end(state: &SolveState, maze: &MazeState, cell: UVec2) -> bool {
+        cell != state.start
+            && cell != state.goal
+            && !state.closed(cell)
+            && maze
+                .passages(cell)
+                .into_iter()
+                .filter(|d| !state.closed(d.offset(cell)))
+                .count()
+                == 1
+    }
+
+    /// Searches the cells that are left for a path from the start to the goal.
+    fn find_path(state: &SolveState, maze: &MazeState) -> Option<Vec<UVec2>> {
+        let mut queue = VecDeque::from([state.start]);
+        let mut parents = FxHashMap::default();
+
+        parents.insert(state.start, state.start);
+
+        while let Some(cell) = queue.pop_front() {
+            if cell == state.goal {
+                return Some(reconstruct_path(&parents, state.start, state.goal));
+            }
+
+            for dir in maze.passages(cell) {
+                let next = dir.offset(cell);
+
+                if state.closed(next) {
+                    continue;
+                }
+
+                if let Entry::Vacant(e) = parents.entry(next) {
+                    e.insert(cell);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl Solver for DeadEndFilling {
+    fn initialize(&mut self, state: &mut SolveState, maze: &MazeState) {
+        self.dead_ends.clear();
+
+        if !maze.contains(state.start) || !maze.contains(state.goal) {
+            state.finish(None);
+            return;
+        }
+
+        for y in (0..maze.size.y).rev() {
+            for x in (0..maze.size.x).rev() {
+                let cell = UVec2::new(x, y);
+
+                if DeadEndFilling::is_dead_end(state, maze, cell) {
+                    self.dead_ends.push(cell);
+                }
+            }
+        }
+    }
+
+    fn step(&mut self, state: &mut SolveState, maze: &MazeState) {
+        // Filling one dead end can turn its neighbor into the next one, which is then filled on a later step.
+        while let Some(cell) = self.dead_ends.pop() {
+            if !DeadEndFilling::is_dead_end(state, maze, cell) {
+                continue;
+            }
+
+            state.head = Some(cell);
+            state.set_closed(cell);
+
+            for dir in maze.passages(cell) {
+                let next = dir.offset(cell);
+
+                if DeadEndFilling::is_dead_end(state, maze, next) {
+                    self.dead_ends.push(next);
+                }
+            }
+
+            return;
+        }
+
+        let solution = DeadEndFilling::find_path(state, maze).map(|path| Solution {
+            cost: path.len() as u32 - 1,
+            path,
+            explored: state.explored(),
+        });
+
+        if let Some(solution) = &solution {
+            for &cell in &solution.path {
+                state.set_open(cell);
+            }
+        }
+
+        state.finish(solution);
+    }
+}
@@ -0,0 +1,38 @@
+use glam::UVec2;
+
+use crate::maze::MazeState;
+
+use super::DistanceMap;
+
+/// Finds the two cells that are farthest apart with two breadth first searches: one from an arbitrary cell to find
+/// the cell farthest from it, and one from that cell to find the other end. The result is exact for perfect mazes,
+/// and a good approximation otherwise.
+///
+/// Returns `None` if no cell has any passages.
+pub fn diameter(maze: &MazeState) -> Option<(UVec2, UVec2)> {
+    farthest_pair(maze, |_| true)
+}
+
+/// Like [`diameter`], but only considers cells along the outer wall, so that the ends can be turned into an entrance
+/// and an exit.
+pub fn boundary_diameter(maze: &MazeState) -> Option<(UVec2, UVec2)> {
+    farthest_pair(maze, |cell| !maze.edges(cell).is_empty())
+}
+
+fn farthest_pair(maze: &MazeState, candidate: impl Fn(UVec2) -> bool) -> Option<(UVec2, UVec2)> {
+    let cells = || (0..maze.size.y).flat_map(|y| (0..maze.size.x).map(move |x| UVec2::new(x, y)));
+    let origin = cells().find(|&c| candidate(c) && !maze.passages(c).is_empty())?;
+
+    let farthest = |from: UVec2| {
+        let map = DistanceMap::new(maze, from);
+
+        cells()
+            .filter(|&c| candidate(c))
+            .filter_map(|c| map.get(c).map(|d| (d, c)))
+            .fold((0, from), |best, next| if next.0 > best.0 { next } else { best })
+            .1
+    };
+
+    let start = farthest(origin);
+    Some((start, farthest(start)))
+}
@@ -0,0 +1,240 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use glam::UVec2;
+use rand::{rngs::SmallRng, SeedableRng};
+use rustc_hash::FxHashMap;
+
+use crate::{maze::MazeState, noise::ValueNoise};
+
+use super::{reconstruct_path, Solution, SolveState, Solver};
+
+/// How much entering each cell costs [`Dijkstra`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum DijkstraWeights {
+    /// Every cell costs one, which finds the same paths as breadth first search.
+    #[default]
+    Uniform,
+    /// A weight for every cell, indexed by `y * width + x`. Missing cells have a weight of one.
+    Cells(Vec<u32>),
+    /// Weights from one to `max` that follow smooth value noise with features roughly `scale` cells wide, like
+    /// rough terrain that is cheaper to walk around than through.
+    Noise { scale: f32, max: u32 },
+}
+
+/// Expands cells in order of their cost from the start, where entering a cell costs its weight.
+#[derive(Debug, Clone, Default)]
+pub struct Dijkstra {
+    seed: u64,
+    weights: DijkstraWeights,
+    costs: Vec<u32>,
+    search: BestFirst,
+}
+
+impl Dijkstra {
+    /// Creates a solver where every cell costs one.
+    pub fn new() -> Dijkstra {
+        Dijkstra::default()
+    }
+
+    /// Creates a solver that weighs cells with `weights`, where `seed` picks the noise for
+    /// [`DijkstraWeights::Noise`].
+    pub fn with_weights(seed: u64, weights: DijkstraWeights) -> Dijkstra {
+        Dijkstra {
+            seed,
+            weights,
+            ..Dijkstra::default()
+        }
+    }
+}
+
+impl Solver for Dijkstra {
+    fn initialize(&mut self, state: &mut SolveState, maze: &MazeState) {
+        self.costs = match &self.weights {
+            DijkstraWeights::Uniform => Vec::new(),
+            DijkstraWeights::Cells(cells) => cells.clone(),
+            DijkstraWeights::Noise { scale, max } => {
+                let noise = ValueNoise::new(&mut SmallRng::seed_from_u64(self.seed), maze.size, *scale);
+                let range = max.saturating_sub(1) as f32;
+
+                (0..maze.size.y)
+                    .flat_map(|y| (0..maze.size.x).map(move |x| UVec2::new(x, y)))
+                    .map(|c| ((noise.sample(c.as_vec2() + 0.5) * range).round() as u32).saturating_add(1))
+                    .collect()
+            },
+        };
+
+        self.search.initialize(state, maze, 0);
+    }
+
+    fn step(&mut self, state: &mut SolveState, maze: &MazeState) {
+        let costs = &self.costs;
+        let weight = |c: UVec2| costs.get((c.y * maze.size.x + c.x) as usize).copied().unwrap_or(1);
+
+        self.search.step(state, maze, weight, |_| 0);
+    }
+}
+
+/// A search that expands cells in order of their cost so far plus a heuristic, which must never overestimate the
+/// remaining cost.
+#[derive(Debug, Clone, Default)]
+pub(super) struct BestFirst {
+    open: BinaryHeap<(Reverse<u32>, u32, [u32; 2])>,
+    costs: FxHashMap<UVec2, u32>,
+    parents: FxHashMap<UVec2, UVec2>,
+}
+
+impl BestFirst {
+    pub(super) fn initialize(&mut self, state: &mut SolveState, maze: &MazeState, estimate: u32) {
+        self.open.clear();
+        self.costs.clear();
+        self.parents.clear();
+
+        if !maze.contains(state.start) || !maze.contains(state.goal) {
+            state.finish(None);
+            return;
+        }
+
+        self.open.push((Reverse(estimate), 0, state.start.to_array()));
+        self.costs.insert(state.start, 0);
+        self.parents.insert(state.start, state.start);
+        state.set_open(state.start);
+    }
+
+    pub(super) fn step(
+        &mut self,
+        state: &mut SolveState,
+        maze: &MazeState,
+        weight: impl Fn(UVec2) -> u32,
+        heuristic: impl Fn(UVec2) -> u32,
+    ) {
+        // Skip entries that were superseded by a cheaper route to the same cell.
+        let (cost, cell) = loop {
+            let Some((_, cost, cell)) = self.open.pop() else {
+                state.finish(None);
+                return;
+            };
+
+            let cell = UVec2::from_array(cell);
+
+            if cost <= self.costs[&cell] {
+                break (cost, cell);
+            }
+        };
+
+        state.head = Some(cell);
+        state.unset_open(cell);
+        state.set_closed(cell);
+
+        if cell == state.goal {
+            state.finish(Some(Solution {
+                path: reconstruct_path(&self.parents, state.start, state.goal),
+                cost,
+                explored: state.explored(),
+            }));
+            return;
+        }
+
+        for dir in maze.passages(cell) {
+            let next = dir.offset(cell);
+            let next_cost = cost.saturating_add(weight(next));
+
+            if self.costs.get(&next).is_none_or(|&c| next_cost < c) {
+                self.costs.insert(next, next_cost);
+                self.parents.insert(next, cell);
+
+                // Ties on the estimate go to the cell with the higher cost, as it is likely closer to the goal.
+                self.open.push((
+                    Reverse(next_cost.saturating_add(heuristic(next))),
+                    next_cost,
+                    next.to_array(),
+                ));
+                state.set_open(next);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        direction::Direction,
+        solve::{MazeSolver, SolverLabel, SolverOptions},
+    };
+
+    use super::*;
+
+    /// A 3x2 ring, where the goal is two steps east of the start along the bottom row or four steps around the top.
+    fn ring() -> MazeState {
+        MazeState::from_passages(
+            UVec2::new(3, 2),
+            &[
+                (UVec2::new(0, 0), Direction::East),
+                (UVec2::new(1, 0), Direction::East),
+                (UVec2::new(0, 0), Direction::North),
+                (UVec2::new(0, 1), Direction::East),
+                (UVec2::new(1, 1), Direction::East),
+                (UVec2::new(2, 1), Direction::South),
+            ],
+        )
+    }
+
+    #[test]
+    fn weights_pick_cheaper_longer_path() {
+        let maze = ring();
+        let (start, goal) = (UVec2::new(0, 0), UVec2::new(2, 0));
+
+        let shortest = Dijkstra::new().solve(start, goal, &maze).unwrap();
+        assert_eq!(shortest.path, [start, UVec2::new(1, 0), goal]);
+        assert_eq!(shortest.cost, 2);
+
+        // Make the middle of the bottom row expensive, so going around the top is cheaper despite being longer.
+        let mut weights = vec![1; 6];
+        weights[1] = 10;
+
+        let options = SolverOptions {
+            weights: DijkstraWeights::Cells(weights),
+        };
+        let cheapest = MazeSolver::from_label(SolverLabel::Dijkstra, 0, &options)
+            .solve(start, goal, &maze)
+            .unwrap();
+
+        assert_eq!(
+            cheapest.path,
+            [start, UVec2::new(0, 1), UVec2::new(1, 1), UVec2::new(2, 1), goal]
+        );
+        assert_eq!(cheapest.cost, 4);
+    }
+
+    #[test]
+    fn huge_weights_saturate() {
+        let maze = ring();
+        let options = SolverOptions {
+            weights: DijkstraWeights::Cells(vec![u32::MAX; 6]),
+        };
+
+        let solution = MazeSolver::from_label(SolverLabel::Dijkstra, 0, &options)
+            .solve(UVec2::new(0, 0), UVec2::new(2, 0), &maze)
+            .unwrap();
+
+        assert_eq!(solution.cost, u32::MAX);
+    }
+
+    #[test]
+    fn noise_weights_stay_in_range() {
+        let maze = ring();
+        let options = SolverOptions {
+            weights: DijkstraWeights::Noise { scale: 1.0, max: 5 },
+        };
+
+        let solution = MazeSolver::from_label(SolverLabel::Dijkstra, 7, &options)
+            .solve(UVec2::new(0, 0), UVec2::new(2, 0), &maze)
+            .unwrap();
+
+        let steps = solution.path.len() as u32 - 1;
+        assert!(
+            (steps..=5 * steps).contains(&solution.cost),
+            "cost {} out of range",
+            solution.cost
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use std::collections::VecDeque;
+
+use glam::UVec2;
+
+use crate::maze::MazeState;
+
+/// The number of steps from one cell to every cell that can be reached from it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DistanceMap {
+    pub origin: UVec2,
+    /// The distance to the cell farthest from the origin.
+    pub max: u32,
+    /// A cell at distance [`DistanceMap::max`], which is the first one found if there are several.
+    pub farthest: UVec2,
+    size: UVec2,
+    distances: Vec<Option<u32>>,
+}
+
+impl DistanceMap {
+    /// Runs a breadth first search from `origin` through the open passages of the maze.
+    pub fn new(maze: &MazeState, origin: UVec2) -> DistanceMap {
+        let mut map = DistanceMap {
+            origin,
+            max: 0,
+            farthest: origin,
+            size: maze.size,
+            distances: vec![None; (maze.size.x * maze.size.y) as usize],
+        };
+
+        if !maze.contains(origin) {
+            return map;
+        }
+
+        let mut queue = VecDeque::from([origin]);
+        let index = map.index(origin);
+        map.distances[index] = Some(0);
+
+        while let Some(cell) = queue.pop_front() {
+            let distance = map.distances[map.index(cell)].unwrap();
+
+            if distance > map.max {
+                map.max = distance;
+                map.farthest = cell;
+            }
+
+            for dir in maze.passages(cell) {
+                let next = dir.offset(cell);
+                let index = map.index(next);
+
+                if map.distances[index].is_none() {
+                    map.distances[index] = Some(distance + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        map
+    }
+
+    #[inline]
+    fn index(&self, cell: UVec2) -> usize {
+        (cell.y * self.size.x + cell.x) as usize
+    }
+
+    /// The distance from the origin to `cell`, or `None` if it cannot be reached or is outside the maze.
+    #[inline]
+    pub fn get(&self, cell: UVec2) -> Option<u32> {
+        if !cell.cmplt(self.size).all() {
+            return None;
+        }
+
+        self.distances[self.index(cell)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::direction::Direction;
+
+    use super::*;
+
+    #[test]
+    fn cells_outside_maze_are_unreachable() {
+        let maze = MazeState::from_passages(UVec2::splat(4), &[(UVec2::ZERO, Direction::North)]);
+        let map = DistanceMap::new(&maze, UVec2::ZERO);
+
+        assert_eq!(map.get(UVec2::Y), Some(1));
+        assert_eq!(map.get(UVec2::new(4, 0)), None);
+        assert_eq!(map.get(UVec2::new(0, 4)), None);
+    }
+}
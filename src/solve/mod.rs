@@ -0,0 +1,286 @@
+use std::{collections::HashSet, str::FromStr};
+
+use glam::UVec2;
+use rustc_hash::FxHashMap;
+
+use crate::maze::MazeState;
+
+mod astar;
+mod bfs;
+mod bidirectional;
+mod dead_end_filling;
+mod diameter;
+mod dijkstra;
+mod distance_map;
+mod tremaux;
+mod wall_follower;
+
+pub use astar::AStar;
+pub use bfs::BreadthFirstSearch;
+pub use bidirectional::Bidirectional;
+pub use dead_end_filling::DeadEndFilling;
+pub use diameter::{boundary_diameter, diameter};
+pub use dijkstra::{Dijkstra, DijkstraWeights};
+pub use distance_map::DistanceMap;
+pub use tremaux::Tremaux;
+pub use wall_follower::{Hand, WallFollower};
+
+/// A path found by a [`Solver`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solution {
+    /// The cells on the path, in order from the start to the goal.
+    pub path: Vec<UVec2>,
+    /// The total cost of the path, which is its number of steps unless the solver weighs cells.
+    pub cost: u32,
+    /// Number of cells the solver expanded before it reached the goal.
+    pub explored: usize,
+}
+
+/// The progress of a [`Solver`], which is what gets drawn while it runs. Solvers that walk through the maze mark
+/// every cell they have been to as closed, and the cells on their current route as open.
+#[derive(Debug, Clone)]
+pub struct SolveState {
+    pub start: UVec2,
+    pub goal: UVec2,
+    pub head: Option<UVec2>,
+    pub finished: bool,
+    pub solution: Option<Solution>,
+    /// The cell where a [`Bidirectional`] search found the best connection between its two sides so far.
+    pub meeting: Option<UVec2>,
+    open: HashSet<UVec2>,
+    closed: HashSet<UVec2>,
+    open_backward: HashSet<UVec2>,
+    closed_backward: HashSet<UVec2>,
+}
+
+impl SolveState {
+    #[inline]
+    pub fn new(start: UVec2, goal: UVec2) -> SolveState {
+        SolveState {
+            start,
+            goal,
+            head: None,
+            finished: false,
+            solution: None,
+            meeting: None,
+            open: HashSet::new(),
+            closed: HashSet::new(),
+            open_backward: HashSet::new(),
+            closed_backward: HashSet::new(),
+        }
+    }
+
+    #[inline]
+    pub fn set_open(&mut self, cell: UVec2) {
+        self.open.insert(cell);
+    }
+
+    #[inline]
+    pub fn unset_open(&mut self, cell: UVec2) {
+        self.open.remove(&cell);
+    }
+
+    #[inline]
+    pub fn set_closed(&mut self, cell: UVec2) {
+        self.closed.insert(cell);
+    }
+
+    #[inline]
+    pub fn open(&self, cell: UVec2) -> bool {
+        self.open.contains(&cell)
+    }
+
+    #[inline]
+    pub fn closed(&self, cell: UVec2) -> bool {
+        self.closed.contains(&cell)
+    }
+
+    /// Like [`SolveState::set_open`], for the side of a [`Bidirectional`] search that starts at the goal.
+    #[inline]
+    pub fn set_open_backward(&mut self, cell: UVec2) {
+        self.open_backward.insert(cell);
+    }
+
+    #[inline]
+    pub fn unset_open_backward(&mut self, cell: UVec2) {
+        self.open_backward.remove(&cell);
+    }
+
+    #[inline]
+    pub fn set_closed_backward(&mut self, cell: UVec2) {
+        self.closed_backward.insert(cell);
+    }
+
+    #[inline]
+    pub fn open_backward(&self, cell: UVec2) -> bool {
+        self.open_backward.contains(&cell)
+    }
+
+    #[inline]
+    pub fn closed_backward(&self, cell: UVec2) -> bool {
+        self.closed_backward.contains(&cell)
+    }
+
+    /// Number of cells that have been expanded so far.
+    #[inline]
+    pub fn explored(&self) -> usize {
+        self.closed.len() + self.closed_backward.len()
+    }
+
+    #[inline]
+    pub fn finish(&mut self, solution: Option<Solution>) {
+        self.finished = true;
+        self.head = None;
+        self.solution = solution;
+    }
+}
+
+pub trait Solver {
+    /// Starts a new search. Solvers may look at the whole maze here, so it must not change until the search is done.
+    fn initialize(&mut self, state: &mut SolveState, maze: &MazeState);
+
+    fn step(&mut self, state: &mut SolveState, maze: &MazeState);
+
+    /// Finds a path from `start` to `goal` through the open passages of the maze, if there is one.
+    fn solve(&mut self, start: UVec2, goal: UVec2, maze: &MazeState) -> Option<Solution> {
+        let mut state = SolveState::new(start, goal);
+
+        self.initialize(&mut state, maze);
+        while !state.finished {
+            self.step(&mut state, maze);
+        }
+
+        state.solution
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SolverLabel {
+    BreadthFirstSearch,
+    Dijkstra,
+    #[default]
+    AStar,
+    LeftWallFollower,
+    RightWallFollower,
+    Tremaux,
+    DeadEndFilling,
+    BidirectionalBreadthFirstSearch,
+    BidirectionalAStar,
+}
+
+impl SolverLabel {
+    pub const ALL: &'static [SolverLabel] = &[
+        SolverLabel::BreadthFirstSearch,
+        SolverLabel::Dijkstra,
+        SolverLabel::AStar,
+        SolverLabel::LeftWallFollower,
+        SolverLabel::RightWallFollower,
+        SolverLabel::Tremaux,
+        SolverLabel::DeadEndFilling,
+        SolverLabel::BidirectionalBreadthFirstSearch,
+        SolverLabel::BidirectionalAStar,
+    ];
+
+    /// A short, lowercase name suitable for command lines and file names.
+    pub fn name(self) -> &'static str {
+        match self {
+            SolverLabel::BreadthFirstSearch => "bfs",
+            SolverLabel::Dijkstra => "dijkstra",
+            SolverLabel::AStar => "astar",
+            SolverLabel::LeftWallFollower => "wall-left",
+            SolverLabel::RightWallFollower => "wall-right",
+            SolverLabel::Tremaux => "tremaux",
+            SolverLabel::DeadEndFilling => "dead-end-filling",
+            SolverLabel::BidirectionalBreadthFirstSearch => "bidirectional-bfs",
+            SolverLabel::BidirectionalAStar => "bidirectional-astar",
+        }
+    }
+}
+
+impl FromStr for SolverLabel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SolverLabel::ALL
+            .iter()
+            .copied()
+            .find(|label| label.name() == s)
+            .ok_or_else(|| format!("unknown solver `{s}`"))
+    }
+}
+
+/// Parameters for the solvers that take them. Solvers ignore the options that do not apply to them.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SolverOptions {
+    /// How much entering each cell costs [`Dijkstra`].
+    pub weights: DijkstraWeights,
+}
+
+#[derive(Debug, Clone)]
+pub enum MazeSolver {
+    BreadthFirstSearch(BreadthFirstSearch),
+    Dijkstra(Dijkstra),
+    AStar(AStar),
+    WallFollower(WallFollower),
+    Tremaux(Tremaux),
+    DeadEndFilling(DeadEndFilling),
+    Bidirectional(Bidirectional),
+}
+
+impl MazeSolver {
+    /// Creates the solver for `label`. The `seed` is only used by options with a random part, such as
+    /// [`DijkstraWeights::Noise`].
+    pub fn from_label(label: SolverLabel, seed: u64, options: &SolverOptions) -> MazeSolver {
+        match label {
+            SolverLabel::BreadthFirstSearch => MazeSolver::BreadthFirstSearch(BreadthFirstSearch::new()),
+            SolverLabel::Dijkstra => MazeSolver::Dijkstra(Dijkstra::with_weights(seed, options.weights.clone())),
+            SolverLabel::AStar => MazeSolver::AStar(AStar::new()),
+            SolverLabel::LeftWallFollower => MazeSolver::WallFollower(WallFollower::new(Hand::Left)),
+            SolverLabel::RightWallFollower => MazeSolver::WallFollower(WallFollower::new(Hand::Right)),
+            SolverLabel::Tremaux => MazeSolver::Tremaux(Tremaux::new()),
+            SolverLabel::DeadEndFilling => MazeSolver::DeadEndFilling(DeadEndFilling::new()),
+            SolverLabel::BidirectionalBreadthFirstSearch => MazeSolver::Bidirectional(Bidirectional::new(false)),
+            SolverLabel::BidirectionalAStar => MazeSolver::Bidirectional(Bidirectional::new(true)),
+        }
+    }
+}
+
+impl Solver for MazeSolver {
+    fn initialize(&mut self, state: &mut SolveState, maze: &MazeState) {
+        match self {
+            MazeSolver::BreadthFirstSearch(s) => s.initialize(state, maze),
+            MazeSolver::Dijkstra(s) => s.initialize(state, maze),
+            MazeSolver::AStar(s) => s.initialize(state, maze),
+            MazeSolver::WallFollower(s) => s.initialize(state, maze),
+            MazeSolver::Tremaux(s) => s.initialize(state, maze),
+            MazeSolver::DeadEndFilling(s) => s.initialize(state, maze),
+            MazeSolver::Bidirectional(s) => s.initialize(state, maze),
+        }
+    }
+
+    fn step(&mut self, state: &mut SolveState, maze: &MazeState) {
+        match self {
+            MazeSolver::BreadthFirstSearch(s) => s.step(state, maze),
+            MazeSolver::Dijkstra(s) => s.step(state, maze),
+            MazeSolver::AStar(s) => s.step(state, maze),
+            MazeSolver::WallFollower(s) => s.step(state, maze),
+            MazeSolver::Tremaux(s) => s.step(state, maze),
+            MazeSolver::DeadEndFilling(s) => s.step(state, maze),
+            MazeSolver::Bidirectional(s) => s.step(state, maze),
+        }
+    }
+}
+
+/// Walks back from `goal` to `start` through `parents`, which maps every reached cell to the cell it was reached from.
+fn reconstruct_path(parents: &FxHashMap<UVec2, UVec2>, start: UVec2, goal: UVec2) -> Vec<UVec2> {
+    let mut path = vec![goal];
+    let mut cell = goal;
+
+    while cell != start {
+        cell = parents[&cell];
+        path.push(cell);
+    }
+
+    path.reverse();
+    path
+}
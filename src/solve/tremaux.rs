@@ -0,0 +1,90 @@
+use glam::UVec2;
+use rustc_hash::FxHashMap;
+
+use crate::{direction::Direction, maze::MazeState};
+
+use super::{Solution, SolveState, Solver};
+
+/// Walks through the maze marking every passage each time it is taken. It never takes a passage a third time, and
+/// turns back when it reaches a cell it has been to before, so it explores the maze like a depth first search and
+/// terminates even in mazes with loops.
+#[derive(Debug, Clone, Default)]
+pub struct Tremaux {
+    marks: FxHashMap<u32, u8>,
+    route: Vec<UVec2>,
+    came_from: Option<Direction>,
+}
+
+impl Tremaux {
+    pub fn new() -> Tremaux {
+        Tremaux::default()
+    }
+
+    fn marks(&self, maze: &MazeState, cell: UVec2, dir: Direction) -> u8 {
+        self.marks.get(&maze.wall_index(cell, dir)).copied().unwrap_or(0)
+    }
+}
+
+impl Solver for Tremaux {
+    fn initialize(&mut self, state: &mut SolveState, maze: &MazeState) {
+        self.marks.clear();
+        self.route.clear();
+        self.came_from = None;
+
+        if !maze.contains(state.start) || !maze.contains(state.goal) {
+            state.finish(None);
+            return;
+        }
+
+        self.route.push(state.start);
+        state.head = Some(state.start);
+        state.set_open(state.start);
+        state.set_closed(state.start);
+    }
+
+    fn step(&mut self, state: &mut SolveState, maze: &MazeState) {
+        let cell = *self.route.last().unwrap();
+
+        if cell == state.goal {
+            state.finish(Some(Solution {
+                cost: self.route.len() as u32 - 1,
+                path: self.route.clone(),
+                explored: state.explored(),
+            }));
+            return;
+        }
+
+        let open = maze.passages(cell);
+        let revisited = |back| open.into_iter().any(|d| d != back && self.marks(maze, cell, d) > 0);
+
+        let dir = match self.came_from {
+            Some(back) if self.marks(maze, cell, back) == 1 && revisited(back) => Some(back),
+            _ => open
+                .into_iter()
+                .filter(|&d| self.marks(maze, cell, d) < 2)
+                .min_by_key(|&d| self.marks(maze, cell, d)),
+        };
+
+        // Every passage has been taken twice, which only happens once the search is back at the start.
+        let Some(dir) = dir else {
+            state.finish(None);
+            return;
+        };
+
+        let next = dir.offset(cell);
+        let marks = self.marks.entry(maze.wall_index(cell, dir)).or_default();
+        *marks += 1;
+
+        if *marks == 2 {
+            self.route.pop();
+            state.unset_open(cell);
+        } else {
+            self.route.push(next);
+            state.set_open(next);
+        }
+
+        self.came_from = Some(-dir);
+        state.head = Some(next);
+        state.set_closed(next);
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::HashSet;
+
+use glam::UVec2;
+use indexmap::IndexSet;
+
+use crate::{direction::Direction, maze::MazeState};
+
+use super::{Solution, SolveState, Solver};
+
+/// The hand a [`WallFollower`] keeps on the wall.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Hand {
+    #[default]
+    Left,
+    Right,
+}
+
+/// Walks through the maze keeping one hand on the wall. This always reaches the goal in a perfect maze, but may walk
+/// in circles forever once the maze has loops, in which case it gives up.
+#[derive(Debug, Clone)]
+pub struct WallFollower {
+    hand: Hand,
+    facing: Direction,
+    seen: HashSet<(UVec2, Direction)>,
+    route: IndexSet<UVec2>,
+}
+
+impl WallFollower {
+    pub fn new(hand: Hand) -> WallFollower {
+        WallFollower {
+            hand,
+            facing: Direction::East,
+            seen: HashSet::new(),
+            route: IndexSet::new(),
+        }
+    }
+
+    /// The directions to try in order of preference, starting with the side of the hand on the wall.
+    fn preference(&self) -> [Direction; 4] {
+        match self.hand {
+            Hand::Left => [
+                self.facing.turn_left(),
+                self.facing,
+                self.facing.turn_right(),
+                -self.facing,
+            ],
+            Hand::Right => [
+                self.facing.turn_right(),
+                self.facing,
+                self.facing.turn_left(),
+                -self.facing,
+            ],
+        }
+    }
+}
+
+impl Solver for WallFollower {
+    fn initialize(&mut self, state: &mut SolveState, maze: &MazeState) {
+        self.facing = Direction::East;
+        self.seen.clear();
+        self.route.clear();
+
+        if !maze.contains(state.start) || !maze.contains(state.goal) {
+            state.finish(None);
+            return;
+        }
+
+        self.route.insert(state.start);
+        state.head = Some(state.start);
+        state.set_open(state.start);
+        state.set_closed(state.start);
+    }
+
+    fn step(&mut self, state: &mut SolveState, maze: &MazeState) {
+        let cell = *self.route.last().unwrap();
+
+        if cell == state.goal {
+            let path: Vec<_> = self.route.iter().copied().collect();
+
+            state.finish(Some(Solution {
+                cost: path.len() as u32 - 1,
+                path,
+                explored: state.explored(),
+            }));
+            return;
+        }
+
+        // Standing in the same cell facing the same way twice means the walk has started repeating itself.
+        let open = maze.passages(cell);
+        let dir = self.preference().into_iter().find(|&d| open.contains(d));
+
+        let Some(dir) = dir.filter(|&d| self.seen.insert((cell, d))) else {
+            state.finish(None);
+            return;
+        };
+
+        let next = dir.offset(cell);
+        self.facing = dir;
+
+        // Keep the route free of loops by cutting it back whenever the walk returns to a cell on it.
+        if let Some(index) = self.route.get_index_of(&next) {
+            for &c in self.route.iter().skip(index + 1) {
+                state.unset_open(c);
+            }
+
+            self.route.truncate(index + 1);
+        } else {
+            self.route.insert(next);
+            state.set_open(next);
+        }
+
+        state.head = Some(next);
+        state.set_closed(next);
+    }
+}
@@ -0,0 +1,199 @@
+use std::fmt;
+
+use glam::UVec2;
+
+use crate::{direction::Direction, disjoint_sets::DisjointSets, maze::MazeState};
+
+/// A way in which a maze fails to be a perfect maze, found by [`validate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Violation {
+    /// The cell opens towards a neighbor that does not open back.
+    Asymmetric { cell: UVec2, dir: Direction },
+    /// The cell opens through the outer wall of the maze.
+    OutwardOpening { cell: UVec2, dir: Direction },
+    /// The cell cannot be reached from the largest connected part of the maze.
+    Disconnected { cell: UVec2 },
+    /// The passage from the cell closes a loop.
+    Cycle { cell: UVec2, dir: Direction },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Violation::Asymmetric { cell, dir } => {
+                write!(f, "cell {cell} opens {dir:?}, but its neighbor does not open back")
+            },
+            Violation::OutwardOpening { cell, dir } => write!(f, "cell {cell} opens {dir:?} through the outer wall"),
+            Violation::Disconnected { cell } => write!(f, "cell {cell} cannot be reached"),
+            Violation::Cycle { cell, dir } => write!(f, "passage {dir:?} of cell {cell} closes a loop"),
+        }
+    }
+}
+
+/// Checks that the maze is perfect: every wall is consistent between the two cells it separates, nothing opens
+/// through the outer wall, and there is exactly one path between any two cells. Cells that were culled into rock are
+/// not part of the maze and are skipped.
+///
+/// Mazes that are not meant to be perfect can ignore the kinds of [`Violation`] they expect, such as
+/// [`Violation::Cycle`] for braided mazes.
+pub fn validate(maze: &MazeState) -> Result<(), Vec<Violation>> {
+    let mut violations = Vec::new();
+    let mut sets = DisjointSets::new((maze.size.x * maze.size.y) as usize);
+    let index = |cell: UVec2| (cell.y * maze.size.x + cell.x) as usize;
+
+    for y in 0..maze.size.y {
+        for x in 0..maze.size.x {
+            let cell = UVec2::new(x, y);
+
+            for dir in maze.neighbors[cell] {
+                let Some(other) = dir.checked_offset(cell).filter(|&c| maze.contains(c)) else {
+                    violations.push(Violation::OutwardOpening { cell, dir });
+                    continue;
+                };
+
+                // A one-sided opening does not join the cells, whichever side it is on.
+                if !maze.neighbors[other].contains(-dir) {
+                    violations.push(Violation::Asymmetric { cell, dir });
+                    continue;
+                }
+
+                // Every passage is seen from both of its cells, so only count it from one of them.
+                if matches!(dir, Direction::East | Direction::North) && !sets.union(index(cell), index(other)) {
+                    violations.push(Violation::Cycle { cell, dir });
+                }
+            }
+        }
+    }
+
+    // Blame the cells outside the largest part, so that a single cut off cell is reported as just that cell.
+    let cells = (0..maze.size.y).flat_map(|y| (0..maze.size.x).map(move |x| UVec2::new(x, y)));
+    let roots: Vec<_> = cells
+        .filter(|&c| !maze.excluded(c))
+        .map(|c| (c, sets.find(index(c))))
+        .collect();
+
+    let mut sizes = vec![0; sets.len()];
+    for &(_, root) in &roots {
+        sizes[root] += 1;
+    }
+
+    // Ties go to the part with the lowest root, to keep the report deterministic.
+    if let Some(largest) = (0..sizes.len()).max_by_key(|&root| (sizes[root], std::cmp::Reverse(root))) {
+        for &(cell, root) in &roots {
+            if root != largest {
+                violations.push(Violation::Disconnected { cell });
+            }
+        }
+    }
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_perfect_maze() {
+        let maze = MazeState::from_passages(
+            UVec2::new(2, 2),
+            &[
+                (UVec2::new(0, 0), Direction::East),
+                (UVec2::new(0, 0), Direction::North),
+                (UVec2::new(1, 0), Direction::North),
+            ],
+        );
+
+        assert_eq!(validate(&maze), Ok(()));
+    }
+
+    #[test]
+    fn finds_asymmetric_wall() {
+        let mut maze = MazeState::new(UVec2::new(2, 1));
+        maze.neighbors[UVec2::ZERO] |= Direction::East.into();
+
+        let cell = UVec2::ZERO;
+        assert_eq!(
+            validate(&maze),
+            Err(vec![
+                Violation::Asymmetric {
+                    cell,
+                    dir: Direction::East
+                },
+                Violation::Disconnected { cell: UVec2::X },
+            ])
+        );
+    }
+
+    #[test]
+    fn finds_mirrored_asymmetric_wall() {
+        for (size, cell, dir) in [
+            (UVec2::new(2, 1), UVec2::X, Direction::West),
+            (UVec2::new(1, 2), UVec2::Y, Direction::South),
+        ] {
+            let mut maze = MazeState::new(size);
+            maze.neighbors[cell] |= dir.into();
+
+            assert_eq!(
+                validate(&maze),
+                Err(vec![
+                    Violation::Asymmetric { cell, dir },
+                    Violation::Disconnected { cell }
+                ])
+            );
+        }
+    }
+
+    #[test]
+    fn finds_outward_opening() {
+        let maze = MazeState::from_passages(
+            UVec2::new(2, 1),
+            &[(UVec2::ZERO, Direction::East), (UVec2::ZERO, Direction::West)],
+        );
+
+        let cell = UVec2::ZERO;
+        assert_eq!(
+            validate(&maze),
+            Err(vec![Violation::OutwardOpening {
+                cell,
+                dir: Direction::West
+            }])
+        );
+    }
+
+    #[test]
+    fn blames_cells_outside_largest_part() {
+        let maze = MazeState::from_passages(UVec2::new(3, 1), &[(UVec2::new(1, 0), Direction::East)]);
+
+        assert_eq!(
+            validate(&maze),
+            Err(vec![Violation::Disconnected { cell: UVec2::ZERO }])
+        );
+    }
+
+    #[test]
+    fn finds_cycle() {
+        let maze = MazeState::from_passages(
+            UVec2::new(2, 2),
+            &[
+                (UVec2::new(0, 0), Direction::East),
+                (UVec2::new(0, 0), Direction::North),
+                (UVec2::new(1, 0), Direction::North),
+                (UVec2::new(0, 1), Direction::East),
+            ],
+        );
+
+        let cell = UVec2::new(0, 1);
+        assert_eq!(
+            validate(&maze),
+            Err(vec![Violation::Cycle {
+                cell,
+                dir: Direction::East
+            }])
+        );
+    }
+}
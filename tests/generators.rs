@@ -0,0 +1,180 @@
+use maze_algorithms::{
+    algorithms::{
+        Algorithm, AlgorithmLabel, AlgorithmOptions, GrowingTreePick, GrowingTreeStrategy, HuntOrder, KruskalWeights,
+        LifeRule, MazeAlgorithm, OriginShift,
+    },
+    direction::Corner,
+    glam::UVec2,
+    maze::MazeState,
+    validate, Violation,
+};
+use proptest::prelude::*;
+
+/// The most steps `label` may take on a maze of `cells` cells before it is considered stuck.
+fn step_budget(label: AlgorithmLabel, cells: u64) -> u64 {
+    match label {
+        // Random walks take quadratic time to cover a maze that is a single row or column.
+        AlgorithmLabel::Wilson | AlgorithmLabel::AldousBroder => 16 * cells * cells + 64,
+        // Stops after at most this many generations, whether or not the pattern settled.
+        AlgorithmLabel::CellularAutomaton => 1000,
+        _ => 8 * cells * (cells.ilog2() as u64 + 1) + 16,
+    }
+}
+
+fn generate(label: AlgorithmLabel, seed: u64, size: UVec2, options: &AlgorithmOptions) -> MazeState {
+    let options = AlgorithmOptions {
+        shift_budget: Some(OriginShift::mixing_steps(size)),
+        ..options.clone()
+    };
+
+    let mut maze = MazeState::new(size);
+    let mut algorithm = MazeAlgorithm::from_label(label, seed, &options);
+    algorithm.initialize(&mut maze);
+
+    let budget = step_budget(label, (size.x * size.y) as u64);
+    let mut steps = 0;
+
+    while !maze.finished {
+        assert!(
+            steps < budget,
+            "{} did not finish a {size} maze with seed {seed} in {budget} steps",
+            label.name()
+        );
+
+        algorithm.step(&mut maze);
+        steps += 1;
+    }
+
+    maze
+}
+
+/// Whether `label` may make loops with `options`, such as rooms or several gaps per wall in recursive division.
+fn allows_loops(label: AlgorithmLabel, options: &AlgorithmOptions) -> bool {
+    match label {
+        AlgorithmLabel::CellularAutomaton => true,
+        AlgorithmLabel::RecursiveDivision => options.room_size.cmpge(UVec2::splat(2)).all() || options.gaps > 1,
+        _ => false,
+    }
+}
+
+/// Checks that the maze is perfect, or if `label` may make loops with `options`, that it is at least connected and
+/// its walls are consistent. Either way, no passage may lead into excluded rock.
+fn check(label: AlgorithmLabel, seed: u64, options: &AlgorithmOptions, maze: &MazeState) {
+    for cell in (0..maze.size.y).flat_map(|y| (0..maze.size.x).map(move |x| UVec2::new(x, y))) {
+        for dir in maze.passages(cell) {
+            let other = dir.offset(cell);
+
+            assert!(
+                !maze.excluded(cell) && !maze.excluded(other),
+                "{} made a {} maze with seed {seed} whose passage {dir:?} from {cell} to {other} reaches rock",
+                label.name(),
+                maze.size,
+            );
+        }
+    }
+
+    let violations = match validate(maze) {
+        Ok(()) => return,
+        Err(violations) if allows_loops(label, options) => violations
+            .into_iter()
+            .filter(|v| !matches!(v, Violation::Cycle { .. }))
+            .collect(),
+        Err(violations) => violations,
+    };
+
+    if let Some(violation) = violations.first() {
+        panic!(
+            "{} made an invalid {} maze with seed {seed}: {violation} ({} violations)",
+            label.name(),
+            maze.size,
+            violations.len(),
+        );
+    }
+}
+
+#[test]
+fn tiny_mazes() {
+    for &label in AlgorithmLabel::ALL {
+        for size in (1..=4).flat_map(|x| (1..=4).map(move |y| UVec2::new(x, y))) {
+            for seed in 0..16 {
+                let options = AlgorithmOptions::default();
+                let maze = generate(label, seed, size, &options);
+                check(label, seed, &options, &maze);
+            }
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn mazes_are_perfect(
+        label in prop::sample::select(AlgorithmLabel::ALL),
+        width in 1u32..=24,
+        height in 1u32..=24,
+        seed in any::<u64>(),
+    ) {
+        let options = AlgorithmOptions::default();
+        let maze = generate(label, seed, UVec2::new(width, height), &options);
+        check(label, seed, &options, &maze);
+    }
+
+    #[test]
+    fn options_keep_mazes_perfect(
+        label in prop::sample::select(AlgorithmLabel::ALL),
+        width in 1u32..=16,
+        height in 1u32..=16,
+        seed in any::<u64>(),
+        strategy in {
+            let pick = prop_oneof![
+                Just(GrowingTreePick::Newest),
+                Just(GrowingTreePick::Oldest),
+                Just(GrowingTreePick::Random),
+                Just(GrowingTreePick::Middle),
+            ];
+
+            prop_oneof![
+                pick.clone().prop_map(GrowingTreeStrategy::Single),
+                (pick.clone(), pick, prop_oneof![0.0f32..=1.0, Just(f32::NAN)])
+                    .prop_map(|(first, second, weight)| GrowingTreeStrategy::Mixed { first, second, weight }),
+            ]
+        },
+        weights in prop_oneof![
+            Just(KruskalWeights::Uniform),
+            (0.0f32..=1.0).prop_map(|horizontal| KruskalWeights::Directional { horizontal }),
+            prop::collection::vec(0.0f32..=4.0, 0..=256).prop_map(KruskalWeights::Cells),
+            (1.0f32..=16.0).prop_map(|scale| KruskalWeights::Noise { scale }),
+        ],
+        hybrid_threshold in prop::option::of(0.0f32..=1.0),
+        hunt_order in prop_oneof![Just(HuntOrder::RowMajor), Just(HuntOrder::Random)],
+        bias in prop_oneof![
+            Just(Corner::NorthEast),
+            Just(Corner::NorthWest),
+            Just(Corner::SouthWest),
+            Just(Corner::SouthEast),
+        ],
+        room_size in (0u32..=4, 0u32..=4).prop_map(|(x, y)| UVec2::new(x, y)),
+        split_bias in prop::option::of(0.0f32..=1.0),
+        gaps in 1u32..=4,
+        rule in prop_oneof![
+            Just(LifeRule::MAZE),
+            Just(LifeRule::MAZECTRIC),
+            any::<([bool; 9], [bool; 9])>().prop_map(|(birth, survival)| LifeRule { birth, survival }),
+        ],
+    ) {
+        let options = AlgorithmOptions {
+            strategy,
+            weights,
+            hybrid_threshold,
+            hunt_order,
+            bias,
+            room_size,
+            split_bias,
+            gaps,
+            rule,
+            ..AlgorithmOptions::default()
+        };
+
+        let maze = generate(label, seed, UVec2::new(width, height), &options);
+        check(label, seed, &options, &maze);
+    }
+}